@@ -6,9 +6,13 @@ use anyhow::format_err;
 use ariadne::{Color, Label, Report, ReportKind, Source};
 use clap::Parser;
 use harper_comments::CommentParser;
-use harper_core::linting::{LintGroup, LintGroupConfig, Linter};
+use harper_core::linting::{
+    Autofixer, FixPolicy, GlossaryEntry, LintGroup, LintGroupConfig, Linter, MatcherRule,
+    StylePreset,
+};
 use harper_core::parsers::Markdown;
-use harper_core::{remove_overlaps, Dictionary, Document, FstDictionary};
+use harper_core::readability::ReadabilityStats;
+use harper_core::{remove_overlaps, Dialect, Dictionary, Document, FstDictionary};
 
 #[derive(Debug, Parser)]
 enum Args {
@@ -20,6 +24,41 @@ enum Args {
         /// without further details.
         #[arg(short, long)]
         count: bool,
+        /// A JSON file containing additional replacement rules for the `Matcher` linter.
+        #[arg(long)]
+        matcher_rules: Option<PathBuf>,
+        /// A JSON file containing the project glossary for the `Terminology` linter.
+        #[arg(long)]
+        glossary: Option<PathBuf>,
+        /// The dialect of English to check spelling against (e.g. `british`).
+        #[arg(long)]
+        dialect: Option<Dialect>,
+        /// The style guide to follow (e.g. `google-dev-docs`, `microsoft` or `plain-language`).
+        #[arg(long)]
+        preset: Option<StylePreset>,
+    },
+    /// Apply the suggested fixes to a provided document.
+    Fix {
+        /// The file you wish to fix.
+        file: PathBuf,
+        /// Overwrite the file instead of printing the result.
+        #[arg(short, long)]
+        write: bool,
+        /// Only apply fixes for lints that have exactly one suggestion.
+        #[arg(long)]
+        only_unambiguous: bool,
+        /// A JSON file containing additional replacement rules for the `Matcher` linter.
+        #[arg(long)]
+        matcher_rules: Option<PathBuf>,
+        /// A JSON file containing the project glossary for the `Terminology` linter.
+        #[arg(long)]
+        glossary: Option<PathBuf>,
+        /// The dialect of English to check spelling against (e.g. `british`).
+        #[arg(long)]
+        dialect: Option<Dialect>,
+        /// The style guide to follow (e.g. `google-dev-docs`, `microsoft` or `plain-language`).
+        #[arg(long)]
+        preset: Option<StylePreset>,
     },
     /// Parse a provided document and print the detected symbols.
     Parse {
@@ -31,18 +70,42 @@ enum Args {
         /// The file you wish to display the spans.
         file: PathBuf,
     },
+    /// Print readability metrics, like the Flesch reading ease, for a provided document.
+    Stats {
+        /// The file you wish to measure.
+        file: PathBuf,
+    },
     /// Emit decompressed, line-separated list of words in Harper's dictionary.
     Words,
+    /// Explain what a rule looks for.
+    /// If no rule is provided, list all available rules.
+    Explain {
+        /// The rule to explain (e.g. `AnA` or `an_a`).
+        rule: Option<String>,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     match args {
-        Args::Lint { file, count } => {
+        Args::Lint {
+            file,
+            count,
+            matcher_rules,
+            glossary,
+            dialect,
+            preset,
+        } => {
             let (doc, source) = load_file(&file)?;
 
-            let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+            let config = load_lint_config(
+                matcher_rules.as_deref(),
+                glossary.as_deref(),
+                dialect,
+                preset,
+            )?;
+            let mut linter = LintGroup::new(config, FstDictionary::curated());
             let mut lints = linter.lint(&doc);
 
             if count {
@@ -69,7 +132,7 @@ fn main() -> anyhow::Result<()> {
             for lint in lints {
                 report_builder = report_builder.with_label(
                     Label::new((&filename, lint.span.into()))
-                        .with_message(lint.message)
+                        .with_message(format!("{} ({})", lint.message, lint.rule_id))
                         .with_color(primary_color),
                 );
             }
@@ -79,6 +142,47 @@ fn main() -> anyhow::Result<()> {
 
             Ok(())
         }
+        Args::Fix {
+            file,
+            write,
+            only_unambiguous,
+            matcher_rules,
+            glossary,
+            dialect,
+            preset,
+        } => {
+            let (doc, _) = load_file(&file)?;
+
+            let config = load_lint_config(
+                matcher_rules.as_deref(),
+                glossary.as_deref(),
+                dialect,
+                preset,
+            )?;
+            let dictionary = FstDictionary::curated();
+            let mut linter = LintGroup::new(config, dictionary.clone());
+
+            let policy = if only_unambiguous {
+                FixPolicy::OnlyUnambiguous
+            } else {
+                FixPolicy::FirstSuggestion
+            };
+
+            let result = Autofixer {
+                policy,
+                ..Default::default()
+            }
+            .fix_document(&doc, &mut linter, &mut load_parser(&file)?, &dictionary);
+
+            if write {
+                std::fs::write(&file, result.text_string())?;
+                eprintln!("Applied {} fixes.", result.edits.len());
+            } else {
+                print!("{}", result.text_string());
+            }
+
+            Ok(())
+        }
         Args::Parse { file } => {
             let (doc, _) = load_file(&file)?;
 
@@ -122,6 +226,79 @@ fn main() -> anyhow::Result<()> {
 
             Ok(())
         }
+        Args::Stats { file } => {
+            let (doc, _) = load_file(&file)?;
+            let stats = ReadabilityStats::from_document(&doc);
+
+            println!("Sentences:               {}", stats.sentences);
+            println!("Words:                   {}", stats.words);
+            println!("Syllables:               {}", stats.syllables);
+            println!("Complex words:           {}", stats.complex_words);
+            println!(
+                "Average sentence length: {:.1} words",
+                stats.average_sentence_length()
+            );
+            println!(
+                "Flesch reading ease:     {:.1}",
+                stats.flesch_reading_ease()
+            );
+            println!(
+                "Flesch-Kincaid grade:    {:.1}",
+                stats.flesch_kincaid_grade()
+            );
+            println!("Gunning fog index:       {:.1}", stats.gunning_fog());
+
+            Ok(())
+        }
+        Args::Explain { rule } => {
+            let linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+
+            let Some(rule) = rule else {
+                for metadata in linter.metadata() {
+                    println!("{}: {}", metadata.rule_id, metadata.description.summary);
+                }
+
+                return Ok(());
+            };
+
+            let metadata = linter
+                .get_metadata(&rule)
+                .ok_or(format_err!("Unknown rule: {rule}"))?;
+            let description = &metadata.description;
+
+            println!("{} ({})", description.name, metadata.rule_id);
+            println!();
+            println!("Kind: {}", description.lint_kind);
+            println!(
+                "Enabled by default: {}",
+                if metadata.default_enabled {
+                    "yes"
+                } else {
+                    "no"
+                }
+            );
+            println!();
+            println!("{}", description.summary);
+
+            if !description.long_description.is_empty() {
+                println!();
+                println!("{}", description.long_description);
+            }
+
+            if !description.bad_examples.is_empty() || !description.good_examples.is_empty() {
+                println!();
+            }
+
+            for example in &description.bad_examples {
+                println!("Bad:  {example}");
+            }
+
+            for example in &description.good_examples {
+                println!("Good: {example}");
+            }
+
+            Ok(())
+        }
         Args::Words => {
             let dict = FstDictionary::curated();
 
@@ -142,16 +319,56 @@ fn main() -> anyhow::Result<()> {
 fn load_file(file: &Path) -> anyhow::Result<(Document, String)> {
     let source = std::fs::read_to_string(file)?;
 
-    let mut parser: Box<dyn harper_core::parsers::Parser> =
-        if let Some("md") = file.extension().map(|v| v.to_str().unwrap()) {
-            Box::new(Markdown)
-        } else {
-            Box::new(
-                CommentParser::new_from_filename(file)
-                    .map(Box::new)
-                    .ok_or(format_err!("Could not detect language ID."))?,
-            )
-        };
+    let mut parser = load_parser(file)?;
 
     Ok((Document::new_curated(&source, &mut parser), source))
 }
+
+fn load_parser(file: &Path) -> anyhow::Result<Box<dyn harper_core::parsers::Parser>> {
+    if let Some("md") = file.extension().map(|v| v.to_str().unwrap()) {
+        Ok(Box::new(Markdown))
+    } else {
+        Ok(Box::new(
+            CommentParser::new_from_filename(file)
+                .map(Box::new)
+                .ok_or(format_err!("Could not detect language ID."))?,
+        ))
+    }
+}
+
+fn load_lint_config(
+    matcher_rules: Option<&Path>,
+    glossary: Option<&Path>,
+    dialect: Option<Dialect>,
+    preset: Option<StylePreset>,
+) -> anyhow::Result<LintGroupConfig> {
+    let mut config = LintGroupConfig {
+        dialect,
+        preset,
+        ..Default::default()
+    };
+
+    if let Some(path) = matcher_rules {
+        let rules: Vec<MatcherRule> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+        config
+            .matcher
+            .get_or_insert_with(Default::default)
+            .options_mut()
+            .rules
+            .extend(rules);
+    }
+
+    if let Some(path) = glossary {
+        let glossary: Vec<GlossaryEntry> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+        config
+            .terminology
+            .get_or_insert_with(Default::default)
+            .options_mut()
+            .glossary
+            .extend(glossary);
+    }
+
+    Ok(config)
+}
@@ -0,0 +1,12 @@
+// harper-ignore-next-line
+// This is an test of the suppression directives.
+fn main() {
+    let directive = "harper-disable";
+
+    // This is an test that should be flagged.
+}
+
+// harper-disable an_a
+// This is an test that should not be flagged.
+// harper-enable an_a
+fn test() {}
@@ -0,0 +1,88 @@
+use crate::{CharStringExt, Dictionary, Token, TokenKind};
+
+/// Abbreviations that are nearly always followed by a name or number, so a
+/// period after them never ends a sentence.
+const PREFIXES: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "capt", "sgt", "lt", "st", "mt", "ft", "vs", "eq", "vol",
+    "ch", "pp",
+];
+
+/// Abbreviations that may come at the end of a sentence.
+/// A period after them only ends the sentence when the next word is
+/// capitalized.
+///
+/// Abbreviations that are also ordinary words, like "sat" or "min", are left
+/// out, since the period after them usually does end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "approx", "etc", "cf", "al", "ca", "esp", "incl", "misc", "resp", "viz", "dept", "govt",
+    "univ", "assn", "bros", "inc", "ltd", "corp", "jr", "sr", "avg", "fig", "figs", "jan", "feb",
+    "apr", "jun", "jul", "aug", "sep", "sept", "oct", "nov", "tue", "tues", "thu", "thurs", "fri",
+    "hr", "hrs", "mins", "secs", "oz", "lb", "lbs", "kg", "km", "cm", "mm",
+];
+
+/// Decide whether the period following `word` is part of an abbreviation,
+/// rather than the end of a sentence.
+///
+/// `next` is the first non-whitespace token after the period, if there is one.
+pub(crate) fn is_abbreviation_period(word: &[char], next: Option<&Token>, source: &[char]) -> bool {
+    let Some(next) = next else {
+        return false;
+    };
+
+    let next_is_capitalized = match next.kind {
+        TokenKind::Word(_) => next
+            .span
+            .get_content(source)
+            .first()
+            .is_some_and(|c| c.is_uppercase()),
+        TokenKind::Number(..) => false,
+        // "etc.," or "approx.)" continue the sentence, but "etc.!" does not.
+        TokenKind::Punctuation(_) if !next.kind.is_sentence_terminator() => false,
+        _ => return false,
+    };
+
+    let lower = word.to_lower().iter().collect::<String>();
+
+    if PREFIXES.contains(&lower.as_str()) {
+        return true;
+    }
+
+    if ABBREVIATIONS.contains(&lower.as_str()) {
+        return !next_is_capitalized;
+    }
+
+    // Initials, like the ones in "J. R. R. Tolkien".
+    matches!(word, [c] if c.is_uppercase() && *c != 'I') && next_is_capitalized
+}
+
+/// Decide whether the trailing period of a dotted initialism, like "U.S.",
+/// also ends the sentence it is in.
+///
+/// `next` is the first non-whitespace token after the initialism, if there is
+/// one.
+pub(crate) fn initialism_ends_sentence(
+    initialism: &[char],
+    next: Option<&Token>,
+    source: &[char],
+    dictionary: &impl Dictionary,
+) -> bool {
+    let Some(next) = next.filter(|t| t.kind.is_word()) else {
+        return false;
+    };
+
+    let next_word = next.span.get_content(source);
+
+    if !next_word.first().is_some_and(|c| c.is_uppercase()) {
+        return false;
+    }
+
+    // A lone "I." is the pronoun, not an initial.
+    if initialism == ['I', '.'] {
+        return true;
+    }
+
+    // Otherwise, the capitalized word could be part of a name, like in
+    // "U.S. Army". Only split before words that usually start sentences.
+    let metadata = dictionary.get_word_metadata(&next_word.to_lower());
+    metadata.is_pronoun_noun() || metadata.article || metadata.is_conjunction()
+}
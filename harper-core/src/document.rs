@@ -4,19 +4,24 @@ use std::fmt::Display;
 
 use paste::paste;
 
+use crate::abbreviations::{initialism_ends_sentence, is_abbreviation_period};
 use crate::parsers::{Markdown, Parser, PlainEnglish};
 use crate::patterns::{PatternExt, RepeatingPattern, SequencePattern};
 use crate::punctuation::Punctuation;
+use crate::suppression::Suppressions;
 use crate::token::NumberSuffix;
 use crate::vec_ext::VecExt;
 use crate::Span;
-use crate::{Dictionary, FatToken, FstDictionary, Lrc, Token, TokenKind, TokenStringExt};
+use crate::{
+    Dictionary, FatToken, FstDictionary, Lrc, PosTagger, Token, TokenKind, TokenStringExt,
+};
 
 /// A document containing some amount of lexed and parsed English text.
 #[derive(Debug, Clone)]
 pub struct Document {
     source: Lrc<Vec<char>>,
     tokens: Vec<Token>,
+    suppressions: Suppressions,
 }
 
 impl Default for Document {
@@ -48,11 +53,42 @@ impl Document {
         source: Lrc<Vec<char>>,
         parser: &mut impl Parser,
         dictionary: &impl Dictionary,
+    ) -> Self {
+        let mut document = Self::new_untagged_from_vec(source, parser, dictionary);
+        document.tag_parts_of_speech();
+
+        document
+    }
+
+    /// Parse text with the built-in [`PlainEnglish`] parser and curated
+    /// dictionary, without tagging the part of speech of each word.
+    ///
+    /// Used where only the tokens matter, like when a pattern is built from a
+    /// phrase.
+    pub(crate) fn new_untagged(text: &str) -> Self {
+        let source: Vec<_> = text.chars().collect();
+
+        Self::new_untagged_from_vec(
+            Lrc::new(source),
+            &mut PlainEnglish,
+            &FstDictionary::curated(),
+        )
+    }
+
+    fn new_untagged_from_vec(
+        source: Lrc<Vec<char>>,
+        parser: &mut impl Parser,
+        dictionary: &impl Dictionary,
     ) -> Self {
         let tokens = parser.parse(&source);
 
-        let mut document = Self { source, tokens };
+        let mut document = Self {
+            source,
+            tokens,
+            suppressions: Suppressions::default(),
+        };
         document.parse(dictionary);
+        document.suppressions = Suppressions::new(&document.source, &document.tokens);
 
         document
     }
@@ -92,6 +128,7 @@ impl Document {
         self.condense_contractions();
         self.condense_dotted_initialisms();
         self.condense_number_suffixes();
+        self.mark_abbreviation_periods(dictionary);
         self.match_quotes();
 
         for token in self.tokens.iter_mut() {
@@ -103,6 +140,84 @@ impl Document {
         }
     }
 
+    /// Find the periods that end abbreviations, like the ones in "Dr." and
+    /// "approx.", and mark them so they aren't treated as sentence
+    /// terminators.
+    ///
+    /// Dotted initialisms that end a sentence, like the "U.S." in "I live in
+    /// the U.S. It is big.", are kept whole and marked as the end of the
+    /// sentence instead. See [`TokenContext::sentence_end`].
+    fn mark_abbreviation_periods(&mut self, dictionary: &impl Dictionary) {
+        for idx in (0..self.tokens.len()).rev() {
+            let token = self.tokens[idx];
+
+            if !token.kind.is_word() {
+                continue;
+            }
+
+            let content = token.span.get_content(&self.source);
+            let next = self.tokens[idx + 1..]
+                .iter()
+                .find(|t| !t.kind.is_whitespace());
+
+            if content.len() > 1
+                && content.last() == Some(&'.')
+                && initialism_ends_sentence(content, next, &self.source, dictionary)
+            {
+                self.tokens[idx].context.sentence_end = true;
+                continue;
+            }
+
+            let Some(period) = self.tokens.get(idx + 1) else {
+                continue;
+            };
+
+            if !period.kind.is_period() {
+                continue;
+            }
+
+            let next = self.tokens[idx + 2..]
+                .iter()
+                .find(|t| !t.kind.is_whitespace());
+
+            if is_abbreviation_period(content, next, &self.source) {
+                self.tokens[idx + 1].kind = TokenKind::Punctuation(Punctuation::AbbreviationPeriod);
+            }
+        }
+    }
+
+    /// Use the [`PosTagger`] to record the part of speech each word plays in
+    /// its sentence. See
+    /// [`WordMetadata::set_pos_tag`](crate::WordMetadata::set_pos_tag).
+    fn tag_parts_of_speech(&mut self) {
+        let tagger = PosTagger::curated();
+
+        let mut sentence = Vec::new();
+
+        for idx in 0..=self.tokens.len() {
+            let token = self.tokens.get(idx);
+
+            if let Some(token) = token {
+                if !token.kind.is_whitespace() {
+                    sentence.push(idx);
+                }
+            }
+
+            let at_end = token.is_none_or(|t| t.is_sentence_terminator());
+
+            if at_end && !sentence.is_empty() {
+                let tokens: Vec<Token> = sentence.iter().map(|i| self.tokens[*i]).collect();
+                let tags = tagger.tag_sentence(&tokens, &self.source);
+
+                for (i, tag) in sentence.drain(..).zip(tags) {
+                    if let TokenKind::Word(meta) = &mut self.tokens[i].kind {
+                        meta.set_pos_tag(tag);
+                    }
+                }
+            }
+        }
+    }
+
     /// Convert all sets of newlines greater than 2 to paragraph breaks.
     fn newlines_to_breaks(&mut self) {
         for token in &mut self.tokens {
@@ -213,6 +328,12 @@ impl Document {
         &self.tokens
     }
 
+    /// Get the inline suppression directives (like `harper-ignore-next-line`)
+    /// found in the document.
+    pub fn suppressions(&self) -> &Suppressions {
+        &self.suppressions
+    }
+
     /// Searches for quotation marks and fills the
     /// [`Punctuation::Quote::twin_loc`] field. This is on a best effort
     /// basis.
@@ -536,6 +657,30 @@ impl TokenStringExt for Document {
     fn iter_sentences(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
         self.tokens.iter_sentences()
     }
+
+    fn iter_headings(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_headings()
+    }
+
+    fn iter_list_items(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_list_items()
+    }
+
+    fn iter_block_quotes(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_block_quotes()
+    }
+
+    fn iter_table_cells(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_table_cells()
+    }
+
+    fn iter_link_texts(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_link_texts()
+    }
+
+    fn iter_emphases(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        self.tokens.iter_emphases()
+    }
 }
 
 impl Display for Document {
@@ -553,7 +698,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::Document;
-    use crate::Span;
+    use crate::{Span, TokenStringExt};
 
     fn assert_condensed_contractions(text: &str, final_tok_count: usize) {
         let document = Document::new_plain_english_curated(text);
@@ -651,4 +796,58 @@ mod tests {
     fn parses_short_ellipsis() {
         assert_token_count("..", 1);
     }
+
+    fn assert_sentence_count(source: &str, count: usize) {
+        let document = Document::new_plain_english_curated(source);
+
+        assert_eq!(document.iter_sentences().count(), count);
+    }
+
+    #[test]
+    fn does_not_split_after_titles() {
+        assert_sentence_count("Dr. Smith and Mr. Jones met Prof. Lee.", 1);
+    }
+
+    #[test]
+    fn does_not_split_after_abbreviations() {
+        assert_sentence_count("It costs approx. ten dollars, e.g. for a hat.", 1);
+        assert_sentence_count("Bring pens, paper, etc. to the exam.", 1);
+        assert_sentence_count("He moved to the U.S. in May.", 1);
+    }
+
+    #[test]
+    fn splits_after_abbreviations_ending_sentences() {
+        assert_sentence_count("Bring pens, paper, etc. The exam is long.", 2);
+        assert_sentence_count("I need pens, paper, etc.", 1);
+        assert_sentence_count("I live in the U.S. It is big.", 2);
+        assert_sentence_count("I moved to the U.S. The move was hard.", 2);
+        assert_sentence_count("Meet me at 5 p.m. Then we go.", 2);
+    }
+
+    #[test]
+    fn splits_after_words_that_look_like_abbreviations() {
+        assert_sentence_count("The cat sat. Then it left.", 2);
+        assert_sentence_count("Wait a min. We are not done.", 2);
+    }
+
+    #[test]
+    fn marks_initialisms_that_end_sentences() {
+        let document = Document::new_markdown_curated("- I live in the U.S. It is big.");
+        let tokens = document.get_tokens();
+
+        let initialism = tokens
+            .iter()
+            .position(|t| document.get_span_content_str(t.span) == "U.S.")
+            .unwrap();
+
+        assert!(tokens[initialism].is_sentence_terminator());
+        assert!(tokens[initialism].context.list_item);
+        assert!(tokens[initialism + 1].kind.is_whitespace());
+    }
+
+    #[test]
+    fn does_not_split_after_initials() {
+        assert_sentence_count("The book was written by J. R. R. Tolkien.", 1);
+        assert_sentence_count("It was I. Nobody else was there.", 2);
+    }
 }
@@ -1,6 +1,7 @@
 #![doc = include_str!("../README.md")]
 #![allow(dead_code)]
 
+mod abbreviations;
 mod char_ext;
 mod char_string;
 mod document;
@@ -10,9 +11,12 @@ pub mod linting;
 mod mask;
 pub mod parsers;
 pub mod patterns;
+mod pos_tagger;
 mod punctuation;
+pub mod readability;
 mod span;
 mod spell;
+mod suppression;
 mod sync;
 mod token;
 mod vec_ext;
@@ -24,13 +28,18 @@ pub use char_string::{CharString, CharStringExt};
 pub use document::Document;
 use linting::Lint;
 pub use mask::{Mask, Masker};
+pub use pos_tagger::{PosTag, PosTagger};
 pub use punctuation::{Punctuation, Quote};
 pub use span::Span;
 pub use spell::{Dictionary, FstDictionary, FullDictionary, MergedDictionary};
+pub use suppression::{Suppression, Suppressions};
 pub use sync::Lrc;
-pub use token::{FatToken, Token, TokenKind, TokenStringExt};
+pub use token::{FatToken, Token, TokenContext, TokenKind, TokenStringExt};
 pub use vec_ext::VecExt;
-pub use word_metadata::{AdverbData, ConjunctionData, NounData, Tense, VerbData, WordMetadata};
+pub use word_metadata::{
+    AdverbData, ConjunctionData, Dialect, DialectFlags, NounData, Tense, UnknownDialectError,
+    VerbData, WordMetadata,
+};
 
 /// A utility function that removes overlapping lints in a vector,
 /// keeping the more important ones.
@@ -74,7 +83,7 @@ mod tests {
         let doc = Document::new_plain_english_curated("Ths  tet");
 
         let lint_config = LintGroupConfig {
-            spell_check: Some(true),
+            spell_check: Some(true.into()),
             spaces: Some(true),
             ..LintGroupConfig::none()
         };
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use itertools::Itertools;
 
-use crate::linting::{Lint, LintKind, Linter, Suggestion};
+use crate::linting::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::{Document, TokenStringExt};
 
 #[derive(Debug, Default)]
@@ -52,6 +52,7 @@ impl Linter for AnA {
                     lints.push(Lint {
                         span: first.span,
                         lint_kind: LintKind::Miscellaneous,
+                        rule_id: "AnA".to_string(),
                         suggestions: vec![Suggestion::ReplaceWith(replacement)],
                         message: "Incorrect indefinite article.".to_string(),
                         priority: 31,
@@ -62,6 +63,19 @@ impl Linter for AnA {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "A or An",
+            "Checks that “a” and “an” agree with the sound of the word that follows.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "“An” belongs before words that start with a vowel sound and “a” before words that start with a consonant sound. What matters is the sound, not the letter, so it is “an hour” but “a user”.",
+        )
+        .with_good_example("This is an apple.")
+        .with_bad_example("This is an test.")
+    }
 }
 
 fn to_lower_word(word: &[char]) -> Cow<'_, [char]> {
@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, Linter, Suggestion};
+use crate::parsers::Parser;
+use crate::{Dictionary, Document, Lrc, Span};
+
+/// A single change to a source text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Edit {
+    /// The characters to be replaced.
+    /// May be empty, in which case the edit is a pure insertion.
+    pub span: Span,
+    /// The text to put in place of the span.
+    pub replacement: Vec<char>,
+    /// The rule that produced the lint the edit fixes.
+    pub rule_id: String,
+}
+
+impl Edit {
+    /// Whether two edits cannot both be applied.
+    ///
+    /// Two insertions at the same point conflict, since their order would be
+    /// ambiguous.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.span.overlaps_with(other.span) || self.span.start == other.span.start
+    }
+
+    /// The change in length of the text caused by applying the edit.
+    fn len_delta(&self) -> isize {
+        self.replacement.len() as isize - self.span.len() as isize
+    }
+}
+
+/// Determines which suggestion (if any) is applied for each lint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FixPolicy {
+    /// Apply the first suggestion of every lint.
+    #[default]
+    FirstSuggestion,
+    /// Only apply suggestions for lints that have exactly one.
+    OnlyUnambiguous,
+}
+
+impl FixPolicy {
+    fn choose<'a>(&self, lint: &'a Lint) -> Option<&'a Suggestion> {
+        match self {
+            Self::FirstSuggestion => lint.suggestions.first(),
+            Self::OnlyUnambiguous => match lint.suggestions.as_slice() {
+                [suggestion] => Some(suggestion),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// The outcome of fixing a text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixResult {
+    /// The fixed text.
+    pub text: Vec<char>,
+    /// The edits that were applied, sorted by position.
+    ///
+    /// The spans of edits made during the first pass are relative to the
+    /// original text. Those made in later passes are relative to the output of
+    /// the previous pass.
+    pub edits: Vec<Edit>,
+    /// The number of passes that made changes to the text.
+    pub passes: usize,
+}
+
+impl FixResult {
+    pub fn text_string(&self) -> String {
+        self.text.iter().collect()
+    }
+}
+
+/// Applies the suggestions of many lints to a document at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Autofixer {
+    pub policy: FixPolicy,
+    /// The maximum number of times the document is linted and fixed.
+    /// Fixes can reveal (or cause) new problems, so more than one pass may be
+    /// needed to reach a fixed point.
+    pub max_passes: usize,
+}
+
+impl Default for Autofixer {
+    fn default() -> Self {
+        Self {
+            policy: FixPolicy::default(),
+            max_passes: 4,
+        }
+    }
+}
+
+impl Autofixer {
+    pub fn new(policy: FixPolicy, max_passes: usize) -> Self {
+        Self { policy, max_passes }
+    }
+
+    /// Choose a set of edits that fix as many lints as possible without
+    /// conflicting.
+    ///
+    /// When two lints conflict, the more important one (as determined by its
+    /// priority) wins. The returned edits are sorted by position.
+    pub fn plan(&self, lints: &[Lint]) -> Vec<Edit> {
+        let mut candidates: Vec<(&Lint, Vec<Edit>)> = lints
+            .iter()
+            .filter_map(|lint| {
+                let suggestion = self.policy.choose(lint)?;
+                Some((lint, suggestion_edits(lint, suggestion)))
+            })
+            .filter(|(_, edits)| !edits.is_empty())
+            .collect();
+
+        // Remember, lower priority means higher importance.
+        candidates.sort_by_key(|(lint, _)| (lint.priority, lint.span.start));
+
+        let mut accepted: Vec<Edit> = Vec::new();
+
+        for (_, edits) in candidates {
+            let conflicts = edits
+                .iter()
+                .any(|edit| accepted.iter().any(|a| a.conflicts_with(edit)));
+
+            if !conflicts {
+                accepted.extend(edits);
+            }
+        }
+
+        accepted.sort_by_key(|e| (e.span.start, e.span.end));
+        accepted
+    }
+
+    /// Fix a text in a single pass, using lints that were produced from it.
+    pub fn fix_lints(&self, source: &[char], lints: &[Lint]) -> FixResult {
+        let edits = self.plan(lints);
+
+        FixResult {
+            text: apply_edits(source, &edits),
+            passes: if edits.is_empty() { 0 } else { 1 },
+            edits,
+        }
+    }
+
+    /// Repeatedly lint and fix a document until no more changes can be made or
+    /// [`Self::max_passes`] is reached.
+    ///
+    /// The provided parser and dictionary are used to re-parse the document
+    /// between passes.
+    pub fn fix_document(
+        &self,
+        document: &Document,
+        linter: &mut impl Linter,
+        parser: &mut impl Parser,
+        dictionary: &impl Dictionary,
+    ) -> FixResult {
+        let mut result = FixResult {
+            text: document.get_source().to_vec(),
+            edits: Vec::new(),
+            passes: 0,
+        };
+
+        let mut lints = linter.lint(document);
+
+        while result.passes < self.max_passes {
+            let edits = self.plan(&lints);
+
+            if edits.is_empty() {
+                break;
+            }
+
+            result.text = apply_edits(&result.text, &edits);
+            result.edits.extend(edits);
+            result.passes += 1;
+
+            if result.passes < self.max_passes {
+                let document =
+                    Document::new_from_vec(Lrc::new(result.text.clone()), parser, dictionary);
+                lints = linter.lint(&document);
+            }
+        }
+
+        result
+    }
+}
+
+/// The edits needed to apply a suggestion to a lint.
+fn suggestion_edits(lint: &Lint, suggestion: &Suggestion) -> Vec<Edit> {
+    suggestion
+        .replacements(lint.span)
+        .into_iter()
+        .map(|r| Edit {
+            span: r.span,
+            replacement: r.text,
+            rule_id: lint.rule_id.clone(),
+        })
+        .collect()
+}
+
+/// Apply a set of non-conflicting edits to a text.
+///
+/// Each edit's span is relative to the original text; the offsets are rebased
+/// as the preceding edits change the length of the text.
+pub fn apply_edits(source: &[char], edits: &[Edit]) -> Vec<char> {
+    let mut sorted: Vec<&Edit> = edits.iter().collect();
+    sorted.sort_by_key(|e| (e.span.start, e.span.end));
+
+    let mut text = source.to_vec();
+    let mut offset: isize = 0;
+
+    for edit in sorted {
+        let span = Span::new(
+            (edit.span.start as isize + offset) as usize,
+            (edit.span.end as isize + offset) as usize,
+        );
+
+        text.splice(span.start..span.end, edit.replacement.iter().copied());
+        offset += edit.len_delta();
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_edits, Autofixer, Edit, FixPolicy};
+    use crate::linting::{Lint, LintGroup, LintGroupConfig, Suggestion};
+    use crate::parsers::PlainEnglish;
+    use crate::{Document, FstDictionary, Span};
+
+    fn replace(start: usize, end: usize, with: &str, priority: u8) -> Lint {
+        Lint {
+            span: Span::new(start, end),
+            suggestions: vec![Suggestion::ReplaceWith(with.chars().collect())],
+            priority,
+            ..Default::default()
+        }
+    }
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn rebases_offsets() {
+        let source = chars("a b c");
+        let edits = vec![
+            Edit {
+                span: Span::new(0, 1),
+                replacement: chars("aaa"),
+                rule_id: String::new(),
+            },
+            Edit {
+                span: Span::new(4, 5),
+                replacement: chars("ccc"),
+                rule_id: String::new(),
+            },
+        ];
+
+        let text: String = apply_edits(&source, &edits).into_iter().collect();
+        assert_eq!(text, "aaa b ccc");
+    }
+
+    #[test]
+    fn keeps_more_important_of_overlapping() {
+        let source = chars("hello world");
+        let lints = vec![replace(0, 11, "goodbye", 50), replace(6, 11, "there", 10)];
+
+        let result = Autofixer::default().fix_lints(&source, &lints);
+
+        assert_eq!(result.text_string(), "hello there");
+        assert_eq!(result.edits.len(), 1);
+    }
+
+    #[test]
+    fn only_unambiguous_skips_multiple_suggestions() {
+        let source = chars("teh cat");
+        let mut lint = replace(0, 3, "the", 10);
+        lint.suggestions.push(Suggestion::ReplaceWith(chars("ten")));
+
+        let fixer = Autofixer::new(FixPolicy::OnlyUnambiguous, 1);
+        let result = fixer.fix_lints(&source, &[lint]);
+
+        assert_eq!(result.text_string(), "teh cat");
+        assert_eq!(result.passes, 0);
+    }
+
+    #[test]
+    fn fixes_whole_document() {
+        let dictionary = FstDictionary::curated();
+        let document =
+            Document::new_plain_english("This is an test. There is an cat here.", &dictionary);
+        let mut linter = LintGroup::new(LintGroupConfig::default(), dictionary.clone());
+
+        let result = Autofixer::default().fix_document(
+            &document,
+            &mut linter,
+            &mut PlainEnglish,
+            &dictionary,
+        );
+
+        assert_eq!(result.text_string(), "This is a test. There is a cat here.");
+        assert_eq!(result.edits.len(), 2);
+    }
+}
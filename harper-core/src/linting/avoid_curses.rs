@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Linter, LinterDescription};
 use crate::{Document, TokenStringExt};
 
 #[derive(Debug, Default)]
@@ -12,12 +12,24 @@ impl Linter for AvoidCurses {
             .map(|t| Lint {
                 span: t.span,
                 lint_kind: LintKind::Miscellaneous,
+                rule_id: "AvoidCurses".to_string(),
                 suggestions: vec![],
                 message: "Try to avoid offensive language.".to_string(),
                 priority: 63,
             })
             .collect()
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Avoid Curses",
+            "Looks for profanity.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description("Offensive language is rarely appropriate in professional writing.")
+        .with_good_example("He fell when he rode his bike.")
+        .with_bad_example("He ate shit when he fell off the bike.")
+    }
 }
 
 #[cfg(test)]
@@ -1,23 +1,42 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    patterns::{Pattern, WordPatternGroup},
+    patterns::{Captures, Pattern, WordPatternGroup},
     Token, TokenStringExt,
 };
 
-use super::{Lint, LintKind, PatternLinter};
+use super::{Lint, LintKind, LinterDescription, PatternLinter};
+
+/// Options for the [`BoringWords`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoringWordsConfig {
+    /// The words that should be flagged.
+    pub words: Vec<String>,
+}
+
+impl Default for BoringWordsConfig {
+    fn default() -> Self {
+        Self {
+            words: ["very", "interesting", "several", "most", "many"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
 
 pub struct BoringWords {
     pattern: Box<dyn Pattern>,
 }
 
-impl Default for BoringWords {
-    fn default() -> Self {
+impl BoringWords {
+    pub fn new(config: BoringWordsConfig) -> Self {
         let mut pattern = WordPatternGroup::default();
 
-        pattern.add_word("very");
-        pattern.add_word("interesting");
-        pattern.add_word("several");
-        pattern.add_word("most");
-        pattern.add_word("many");
+        for word in &config.words {
+            pattern.add_word(word);
+        }
 
         Self {
             pattern: Box::new(pattern),
@@ -25,17 +44,29 @@ impl Default for BoringWords {
     }
 }
 
+impl Default for BoringWords {
+    fn default() -> Self {
+        Self::new(BoringWordsConfig::default())
+    }
+}
+
 impl PatternLinter for BoringWords {
     fn pattern(&self) -> &dyn Pattern {
         self.pattern.as_ref()
     }
 
-    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint {
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        source: &[char],
+        _captures: &Captures,
+    ) -> Lint {
         let matched_word = matched_tokens.span().unwrap().get_content_string(source);
 
         Lint {
             span: matched_tokens.span().unwrap(),
             lint_kind: LintKind::Enhancement,
+            rule_id: "BoringWords".to_string(),
             suggestions: vec![],
             message: format!(
                 "“{}” is a boring word. Try something a little more exotic.",
@@ -44,4 +75,39 @@ impl PatternLinter for BoringWords {
             priority: 127,
         }
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Boring Words",
+            "Looks for overused words that add little meaning.",
+            LintKind::Enhancement,
+        )
+        .with_long_description(
+            "Words like “very” and “interesting” are used so often that they rarely add much. A more specific word is often more engaging.",
+        )
+        .with_good_example("This is a fascinating book.")
+        .with_bad_example("This is a very good book.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoringWords, BoringWordsConfig};
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn flags_default_words() {
+        assert_lint_count("This is very interesting.", BoringWords::default(), 2);
+    }
+
+    #[test]
+    fn flags_configured_words() {
+        assert_lint_count(
+            "This is very interesting, but quite dull.",
+            BoringWords::new(BoringWordsConfig {
+                words: vec!["quite".to_string()],
+            }),
+            1,
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{NounData, TokenKind, TokenStringExt, WordMetadata};
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 
 /// A super-simple linter that makes sure you capitalize "I".
 #[derive(Default)]
@@ -24,6 +24,7 @@ impl Linter for CapitalizePersonalPronouns {
                     lints.push(Lint {
                         span: tok.span,
                         lint_kind: LintKind::Capitalization,
+                        rule_id: "CapitalizePersonalPronouns".to_string(),
                         suggestions: vec![Suggestion::ReplaceWith(vec!['I'])],
                         message: "First-person singular pronouns must be capitalized.".to_string(),
                         priority: 31,
@@ -34,6 +35,19 @@ impl Linter for CapitalizePersonalPronouns {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Capitalize Personal Pronouns",
+            "Checks that personal pronouns like “I” are capitalized.",
+            LintKind::Capitalization,
+        )
+        .with_long_description(
+            "The personal pronoun “I” is always capitalized, no matter where it appears in a sentence.",
+        )
+        .with_good_example("I am hungry.")
+        .with_bad_example("i am hungry.")
+    }
 }
 
 #[cfg(test)]
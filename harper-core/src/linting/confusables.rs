@@ -0,0 +1,293 @@
+use super::{Lint, LintKind, Linter, LinterDescription, PatternLinter, Suggestion};
+use crate::patterns::{parse_pattern, Captures, Lookbehind, Pattern};
+use crate::{Document, Token};
+
+const THEIR: &str = "Use “their” to show that something belongs to them.";
+const THERE: &str = "Use “there” to say that something exists or to refer to a place.";
+const THEYRE: &str = "Use “they're”, which is short for “they are”.";
+const ITS: &str = "Use “its” to show that something belongs to it.";
+const ITS_CONTRACTION: &str = "Use “it's”, which is short for “it is” or “it has”.";
+const THAN: &str = "Use “than” to compare things.";
+const THEN: &str = "Use “then” to talk about time or what comes next.";
+
+/// Words that are commonly followed by “than”.
+const COMPARATIVES: &str = r#"word("more"|"less"|"fewer"|"rather"|"other"|"better"|"worse"|"greater"|"larger"|"smaller"|"bigger"|"higher"|"lower"|"faster"|"slower"|"older"|"younger"|"longer"|"shorter"|"easier"|"harder")"#;
+
+/// Prepositions that are commonly followed by a possessive, like “in its place”.
+const PREPOSITIONS: &str =
+    r#"word("of"|"in"|"on"|"for"|"with"|"from"|"by"|"to"|"into"|"about"|"under")"#;
+
+/// Compile one of the patterns below, which are written in the
+/// [pattern language](crate::patterns::parse_pattern).
+fn dsl(source: &str) -> Box<dyn Pattern> {
+    parse_pattern(source).expect("Confusion rules should be valid.")
+}
+
+/// The confusion sets.
+/// Each rule replaces the token its pattern captures as `misused`.
+fn rules() -> Vec<ConfusionRule> {
+    vec![
+        ConfusionRule::new(
+            dsl(
+                r#"misused:"their" _ word("is"|"are"|"was"|"were"|"isn't"|"aren't"|"wasn't"|"weren't")"#,
+            ),
+            "there",
+            THERE,
+        ),
+        ConfusionRule::new(dsl(r#"misused:"their" _ ART"#), "they're", THEYRE),
+        ConfusionRule::new(
+            dsl(r#"misused:"their" _ "going" _ "to""#),
+            "they're",
+            THEYRE,
+        ),
+        ConfusionRule::new(
+            dsl(r#"misused:("there"|"they're") _ "own""#),
+            "their",
+            THEIR,
+        ),
+        // Only finite forms of "be", "have" and "do" are accepted after the
+        // noun, since "they're dogs running around" is correct.
+        ConfusionRule::new(
+            dsl(
+                r#"misused:"they're" _ NOUN _ word("is"|"are"|"was"|"were"|"has"|"have"|"does"|"doesn't"|"isn't"|"aren't")"#,
+            ),
+            "their",
+            THEIR,
+        ),
+        ConfusionRule::new(
+            dsl(r#"misused:"its" _ (ART|word("not"|"been"|"going"))"#),
+            "it's",
+            ITS_CONTRACTION,
+        ),
+        ConfusionRule::new(dsl(r#"misused:"it's" _ "own""#), "its", ITS),
+        ConfusionRule::new(
+            dsl(&format!(
+                r#"{PREPOSITIONS} _ misused:"it's" _ (ADJ _)* NOUN"#
+            )),
+            "its",
+            ITS,
+        ),
+        ConfusionRule::new(
+            dsl(&format!(
+                r#"{COMPARATIVES} _ misused:"then" | misused:"then" _ (word("others"|"before"|"her"|"hers"|"him"|"his") | "last" _ "week")"#
+            )),
+            "than",
+            THAN,
+        ),
+        ConfusionRule::new(
+            dsl(r#"word("and"|"since"|"until"|"till"|"by"|"back") _ misused:"than""#),
+            "then",
+            THEN,
+        ),
+        // Commas end chunks, so they have to be looked for behind the match.
+        ConfusionRule::new(
+            Box::new(Lookbehind::new(
+                dsl("COMMA _"),
+                dsl(r#"misused:"than" _ PRON"#),
+            )),
+            "then",
+            THEN,
+        ),
+    ]
+}
+
+/// A single rule from a confusion set.
+struct ConfusionRule {
+    pattern: Box<dyn Pattern>,
+    replacement: &'static str,
+    message: &'static str,
+}
+
+impl ConfusionRule {
+    fn new(pattern: Box<dyn Pattern>, replacement: &'static str, message: &'static str) -> Self {
+        Self {
+            pattern,
+            replacement,
+            message,
+        }
+    }
+}
+
+impl PatternLinter for ConfusionRule {
+    fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        source: &[char],
+        captures: &Captures,
+    ) -> Lint {
+        let misused = captures
+            .get_tokens("misused", matched_tokens)
+            .and_then(|tokens| tokens.first())
+            .unwrap();
+        let content = misused.span.get_content(source);
+
+        Lint {
+            span: misused.span,
+            lint_kind: LintKind::Miscellaneous,
+            rule_id: "Confusables".to_string(),
+            suggestions: vec![Suggestion::replace_with_match_case(
+                self.replacement.chars().collect(),
+                content,
+            )],
+            message: format!("Did you mean “{}”? {}", self.replacement, self.message),
+            priority: 31,
+        }
+    }
+}
+
+/// Looks for real words that are easily confused with one another, like
+/// “their” and “there”, by the words around them.
+pub struct Confusables {
+    rules: Vec<ConfusionRule>,
+}
+
+impl Default for Confusables {
+    fn default() -> Self {
+        Self { rules: rules() }
+    }
+}
+
+impl Linter for Confusables {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        self.rules
+            .iter_mut()
+            .flat_map(|rule| rule.lint(document))
+            .collect()
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Confusables",
+            "Looks for words that are easily confused with one another, like “their” and “there”.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Homophones like “their”, “there” and “they're” are all real words, so a spell checker cannot tell when the wrong one is used. This rule looks at the words around them to decide which was meant. It covers “their”, “there” and “they're”, “its” and “it's”, and “then” and “than”.",
+        )
+        .with_good_example("It's clear that their team is better than ours.")
+        .with_bad_example("Its clear that there own team is better then ours.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Confusables;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn their_is() {
+        assert_suggestion_result(
+            "Their is a problem with the build.",
+            Confusables::default(),
+            "There is a problem with the build.",
+        );
+    }
+
+    #[test]
+    fn their_going_to() {
+        assert_suggestion_result(
+            "I think their going to win.",
+            Confusables::default(),
+            "I think they're going to win.",
+        );
+    }
+
+    #[test]
+    fn there_own() {
+        assert_suggestion_result(
+            "They built there own house.",
+            Confusables::default(),
+            "They built their own house.",
+        );
+    }
+
+    #[test]
+    fn theyre_car() {
+        assert_suggestion_result(
+            "They're car is red.",
+            Confusables::default(),
+            "Their car is red.",
+        );
+    }
+
+    #[test]
+    fn allows_theyre_before_participle() {
+        assert_lint_count(
+            "They're students learning to code. They're dogs running around the yard.",
+            Confusables::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn its_a() {
+        assert_suggestion_result(
+            "Its a nice day.",
+            Confusables::default(),
+            "It's a nice day.",
+        );
+    }
+
+    #[test]
+    fn in_its_place() {
+        assert_suggestion_result(
+            "Put the book back in it's usual place.",
+            Confusables::default(),
+            "Put the book back in its usual place.",
+        );
+    }
+
+    #[test]
+    fn flags_then_before_comparisons() {
+        assert_suggestion_result(
+            "He runs faster then him.",
+            Confusables::default(),
+            "He runs faster than him.",
+        );
+        assert_suggestion_result(
+            "She has more books then others.",
+            Confusables::default(),
+            "She has more books than others.",
+        );
+    }
+
+    #[test]
+    fn better_then() {
+        assert_suggestion_result(
+            "This is better then the last one.",
+            Confusables::default(),
+            "This is better than the last one.",
+        );
+    }
+
+    #[test]
+    fn and_than() {
+        assert_suggestion_result(
+            "We ate, and than we left.",
+            Confusables::default(),
+            "We ate, and then we left.",
+        );
+    }
+
+    #[test]
+    fn comma_than() {
+        assert_suggestion_result(
+            "We ate, than we left.",
+            Confusables::default(),
+            "We ate, then we left.",
+        );
+    }
+
+    #[test]
+    fn allows_correct_usage() {
+        assert_lint_count(
+            "There are many options. Their car is red, and they're friends. It's raining, so the dog wagged its tail. It is better than that, and then we left.",
+            Confusables::default(),
+            0,
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::token::{NumberSuffix, TokenStringExt};
 use crate::{Document, Span, TokenKind};
 
@@ -19,6 +19,7 @@ impl Linter for CorrectNumberSuffix {
                         output.push(Lint {
                             span: suffix_span,
                             lint_kind: LintKind::Miscellaneous,
+                            rule_id: "CorrectNumberSuffix".to_string(),
                             message: "This number needs a different suffix to sound right."
                                 .to_string(),
                             suggestions: vec![Suggestion::ReplaceWith(correct_suffix.to_chars())],
@@ -31,6 +32,19 @@ impl Linter for CorrectNumberSuffix {
 
         output
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Correct Number Suffix",
+            "Checks that ordinal suffixes match their numbers.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Ordinal numbers take a suffix that depends on their last digits, as in “1st”, “2nd”, “3rd” and “11th”.",
+        )
+        .with_good_example("She came in 2nd place.")
+        .with_bad_example("She came in 2st place.")
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use super::LintKind;
+
+/// Documentation for a linter, used to explain its rule to users.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct LinterDescription {
+    /// A human-readable name for the linter.
+    pub name: String,
+    /// A short, one-sentence summary of what the linter looks for.
+    pub summary: String,
+    /// A longer explanation of the rule and why it matters.
+    pub long_description: String,
+    /// The kind of lints the linter produces.
+    pub lint_kind: LintKind,
+    /// Examples of text the linter accepts.
+    pub good_examples: Vec<String>,
+    /// Examples of text the linter flags.
+    pub bad_examples: Vec<String>,
+}
+
+impl LinterDescription {
+    pub fn new(name: impl ToString, summary: impl ToString, lint_kind: LintKind) -> Self {
+        Self {
+            name: name.to_string(),
+            summary: summary.to_string(),
+            lint_kind,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_long_description(mut self, long_description: impl ToString) -> Self {
+        self.long_description = long_description.to_string();
+        self
+    }
+
+    pub fn with_good_example(mut self, example: impl ToString) -> Self {
+        self.good_examples.push(example.to_string());
+        self
+    }
+
+    pub fn with_bad_example(mut self, example: impl ToString) -> Self {
+        self.bad_examples.push(example.to_string());
+        self
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
+use crate::{
+    CharString, Dialect, DialectFlags, Dictionary, Document, FstDictionary, TokenStringExt,
+};
+
+/// The largest number of edits between two spellings of the same word, like
+/// "centre" and "center".
+const MAX_EDIT_DIST: u8 = 2;
+
+/// Most words that are close to a variant are not variants themselves, so
+/// plenty of candidates need to be looked at.
+const MAX_CANDIDATES: usize = 200;
+
+/// Looks for words spelled the way another dialect of English spells them,
+/// like "colour" in American English.
+///
+/// Nothing is flagged until a dialect is chosen, since either spelling is
+/// fine otherwise.
+pub struct DialectSpelling {
+    dialect: Option<Dialect>,
+    dictionary: Arc<dyn Dictionary>,
+}
+
+impl DialectSpelling {
+    /// Check spelling against a dialect, looking for the variants to suggest
+    /// in the given dictionary.
+    pub fn new(dialect: Dialect, dictionary: Arc<dyn Dictionary>) -> Self {
+        Self {
+            dialect: Some(dialect),
+            dictionary,
+        }
+    }
+
+    /// Find the spelling of a word used in a dialect.
+    fn find_variant(&self, word: &[char], dialect: Dialect) -> Option<CharString> {
+        self.dictionary
+            .fuzzy_match(word, MAX_EDIT_DIST, MAX_CANDIDATES)
+            .into_iter()
+            .find(|result| {
+                // Only words that are spelled differently between dialects
+                // are variants, which rules out unrelated words like "prey"
+                // for "grey".
+                result
+                    .metadata
+                    .dialects
+                    .is_some_and(|dialects| dialects != DialectFlags::ALL)
+                    && result.metadata.is_in_dialect(dialect)
+            })
+            .map(|result| result.word.into())
+    }
+}
+
+impl Default for DialectSpelling {
+    fn default() -> Self {
+        Self {
+            dialect: None,
+            dictionary: FstDictionary::curated(),
+        }
+    }
+}
+
+impl Linter for DialectSpelling {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        let Some(dialect) = self.dialect else {
+            return lints;
+        };
+
+        for word in document.iter_words() {
+            let metadata = word.kind.as_word().unwrap();
+
+            if metadata.is_in_dialect(dialect) {
+                continue;
+            }
+
+            let content = document.get_span_content(word.span);
+
+            lints.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::Spelling,
+                rule_id: "DialectSpelling".to_string(),
+                suggestions: self
+                    .find_variant(content, dialect)
+                    .into_iter()
+                    .map(|variant| Suggestion::replace_with_match_case(variant.to_vec(), content))
+                    .collect(),
+                message: format!(
+                    "“{}” is not how this word is spelled in {} English.",
+                    document.get_span_content_str(word.span),
+                    dialect
+                ),
+                priority: 63,
+            })
+        }
+
+        lints
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Dialect Spelling",
+            "Looks for words spelled the way another dialect of English spells them.",
+            LintKind::Spelling,
+        )
+        .with_long_description(
+            "American, British, Canadian and Australian English spell some words differently, like “color” and “colour” or “organize” and “organise”. This rule keeps a document consistent with the configured `dialect`. It does nothing until one is set.",
+        )
+        .with_good_example("Their favorite color is gray.")
+        .with_bad_example("Their favourite colour is grey.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DialectSpelling;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::{Dialect, FstDictionary};
+
+    fn in_dialect(dialect: Dialect) -> DialectSpelling {
+        DialectSpelling::new(dialect, FstDictionary::curated())
+    }
+
+    #[test]
+    fn flags_british_in_american() {
+        assert_suggestion_result(
+            "What is your favourite colour?",
+            in_dialect(Dialect::American),
+            "What is your favorite color?",
+        );
+    }
+
+    #[test]
+    fn flags_american_in_british() {
+        assert_suggestion_result(
+            "We need to organize the theater.",
+            in_dialect(Dialect::British),
+            "We need to organise the theatre.",
+        );
+    }
+
+    #[test]
+    fn flags_derived_words() {
+        assert_suggestion_result(
+            "They travelled to see the colours.",
+            in_dialect(Dialect::American),
+            "They traveled to see the colors.",
+        );
+    }
+
+    #[test]
+    fn canadian_mixes_spellings() {
+        assert_lint_count(
+            "We organized a trip to see the colours of the harbour.",
+            in_dialect(Dialect::Canadian),
+            0,
+        );
+        assert_lint_count("We organised a trip.", in_dialect(Dialect::Canadian), 1);
+    }
+
+    #[test]
+    fn australian_uses_ise() {
+        assert_suggestion_result(
+            "I realize that the centre is grey.",
+            in_dialect(Dialect::Australian),
+            "I realise that the centre is grey.",
+        );
+    }
+
+    #[test]
+    fn needs_a_dialect() {
+        assert_lint_count(
+            "What is your favourite colour?",
+            DialectSpelling::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_shared_words() {
+        assert_lint_count(
+            "The analyses show that travel is popular.",
+            in_dialect(Dialect::British),
+            0,
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use hashbrown::HashMap;
 
-use super::{Lint, LintKind, PatternLinter, Suggestion};
-use crate::patterns::{Pattern, SequencePattern, WordPatternGroup};
+use super::{Lint, LintKind, LinterDescription, PatternLinter, Suggestion};
+use crate::patterns::{Captures, Pattern, SequencePattern, WordPatternGroup};
 use crate::{Token, TokenStringExt};
 
 pub struct DotInitialisms {
@@ -37,7 +37,12 @@ impl PatternLinter for DotInitialisms {
         self.pattern.as_ref()
     }
 
-    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint {
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        source: &[char],
+        _captures: &Captures,
+    ) -> Lint {
         let found_word_tok = matched_tokens.first().unwrap();
         let found_word = found_word_tok.span.get_content_string(source);
 
@@ -46,11 +51,25 @@ impl PatternLinter for DotInitialisms {
         Lint {
             span: matched_tokens.span().unwrap(),
             lint_kind: LintKind::Formatting,
+            rule_id: "DotInitialisms".to_string(),
             suggestions: vec![Suggestion::ReplaceWith(correction.chars().collect())],
             message: "Initialisms should have dot-separated letters.".to_owned(),
             priority: 63,
         }
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Dot Initialisms",
+            "Checks that initialisms like “e.g.” have dot-separated letters.",
+            LintKind::Formatting,
+        )
+        .with_long_description(
+            "Latin initialisms like “e.g.” and “i.e.” are written with a dot after each letter.",
+        )
+        .with_good_example("Some text here (e.g. more text).")
+        .with_bad_example("Some text here (eg. more text).")
+    }
 }
 
 #[cfg(test)]
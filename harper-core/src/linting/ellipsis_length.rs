@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::TokenStringExt;
 
 /// A linter that checks that an ellipsis doesn't contain too many periods (or
@@ -26,6 +26,7 @@ impl Linter for EllipsisLength {
                 lints.push(Lint {
                     span: tok.span,
                     lint_kind: LintKind::Formatting,
+                    rule_id: "EllipsisLength".to_string(),
                     suggestions: vec![Suggestion::ReplaceWith(vec!['.', '.', '.'])],
                     message: "Horizontal ellipsis must have 3 dots.".to_string(),
                     priority: 31,
@@ -35,6 +36,19 @@ impl Linter for EllipsisLength {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Ellipsis Length",
+            "Checks that ellipses have exactly three dots.",
+            LintKind::Formatting,
+        )
+        .with_long_description(
+            "An ellipsis is always made of three dots. Any more or fewer is likely a typo.",
+        )
+        .with_good_example("Wait for it...")
+        .with_bad_example("Wait for it.....")
+    }
 }
 
 #[cfg(test)]
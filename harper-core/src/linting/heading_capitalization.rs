@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
+use crate::{CharStringExt, Dictionary, Document, FstDictionary, Token, TokenStringExt};
+
+/// Words that are never capitalized inside a title, in either style.
+const ARTICLES_AND_CONJUNCTIONS: [&str; 8] = ["a", "an", "the", "and", "but", "for", "nor", "or"];
+
+/// The AP Stylebook also lowercases conjunctions and prepositions of three
+/// letters or fewer.
+const AP_MINOR_WORDS: [&str; 9] = ["as", "at", "by", "in", "of", "on", "per", "so", "to"];
+
+/// The Chicago Manual of Style lowercases prepositions, no matter their
+/// length.
+const CHICAGO_MINOR_WORDS: [&str; 37] = [
+    "about", "above", "across", "after", "against", "along", "among", "around", "as", "at",
+    "before", "behind", "below", "beneath", "beside", "between", "beyond", "by", "during", "from",
+    "in", "inside", "into", "of", "on", "onto", "over", "per", "through", "to", "toward",
+    "towards", "under", "until", "upon", "with", "within",
+];
+
+/// The ways a heading can be capitalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HeadingStyle {
+    /// Only the first word and proper nouns are capitalized, like "Getting
+    /// started with Harper".
+    #[default]
+    SentenceCase,
+    /// Every word is capitalized, except for articles and short conjunctions
+    /// and prepositions, like "Getting Started With Harper".
+    ApTitleCase,
+    /// Every word is capitalized, except for articles, short conjunctions and
+    /// all prepositions, like "Getting Started with Harper".
+    ChicagoTitleCase,
+}
+
+impl HeadingStyle {
+    fn is_minor_word(&self, word: &str) -> bool {
+        ARTICLES_AND_CONJUNCTIONS.contains(&word)
+            || match self {
+                Self::SentenceCase => false,
+                Self::ApTitleCase => AP_MINOR_WORDS.contains(&word),
+                Self::ChicagoTitleCase => CHICAGO_MINOR_WORDS.contains(&word),
+            }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::SentenceCase => "sentence case",
+            Self::ApTitleCase => "title case (AP style)",
+            Self::ChicagoTitleCase => "title case (Chicago style)",
+        }
+    }
+}
+
+/// Options for the [`HeadingCapitalization`] linter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeadingCapitalizationConfig {
+    pub style: HeadingStyle,
+}
+
+/// Checks that Markdown headings are consistently capitalized in either
+/// sentence case or title case.
+pub struct HeadingCapitalization {
+    config: HeadingCapitalizationConfig,
+    dictionary: Arc<FstDictionary>,
+}
+
+impl HeadingCapitalization {
+    pub fn new(config: HeadingCapitalizationConfig) -> Self {
+        Self {
+            config,
+            dictionary: FstDictionary::curated(),
+        }
+    }
+
+    /// Re-case a single word of a heading, if it needs it.
+    ///
+    /// Words that the dictionary does not know in lowercase, like names, and
+    /// words with capitals after their first letter, like "API" or "iOS", are
+    /// left alone.
+    fn recase_word(&self, word: &Token, source: &[char], capitalize: bool) -> Option<Vec<char>> {
+        let content = word.span.get_content(source);
+        let (first, rest) = content.split_first()?;
+
+        if rest.iter().any(|c| c.is_uppercase()) {
+            return None;
+        }
+
+        let lower = content.to_lower();
+
+        if !self.dictionary.contains_word(&lower) {
+            return None;
+        }
+
+        if capitalize && first.is_lowercase() {
+            Some(first.to_uppercase().chain(rest.iter().copied()).collect())
+        } else if !capitalize && first.is_uppercase() {
+            // The dictionary lists some minor words, like "A", as names too.
+            let is_minor =
+                ARTICLES_AND_CONJUNCTIONS.contains(&lower.iter().collect::<String>().as_str());
+            let is_proper = !is_minor
+                && (word.kind.as_word().unwrap().is_proper_noun()
+                    || self.dictionary.get_word_metadata(&lower).is_proper_noun());
+
+            (!is_proper).then(|| lower.to_vec())
+        } else {
+            None
+        }
+    }
+
+    /// Produce the correctly capitalized text of a heading.
+    fn recase_heading(&self, heading: &[Token], source: &[char]) -> Vec<char> {
+        let style = self.config.style;
+
+        let last_word = heading.last_word_index();
+        let mut after_colon = false;
+        let mut is_first = true;
+        let mut output = Vec::new();
+
+        for (index, token) in heading.iter().enumerate() {
+            let content = token.span.get_content(source);
+
+            if token.kind.is_whitespace() {
+                output.extend_from_slice(content);
+                continue;
+            }
+
+            let starts_phrase = is_first || after_colon;
+            is_first = false;
+            after_colon = token.kind.is_colon();
+
+            if !token.kind.is_word() {
+                output.extend_from_slice(content);
+                continue;
+            }
+
+            let lower: String = content.to_lower().iter().collect();
+
+            let capitalize = if starts_phrase || lower == "i" {
+                true
+            } else if style == HeadingStyle::SentenceCase {
+                false
+            } else if Some(index) == last_word {
+                true
+            } else {
+                !style.is_minor_word(&lower)
+            };
+
+            match self.recase_word(token, source, capitalize) {
+                Some(recased) => output.extend(recased),
+                None => output.extend_from_slice(content),
+            }
+        }
+
+        output
+    }
+}
+
+impl Default for HeadingCapitalization {
+    fn default() -> Self {
+        Self::new(HeadingCapitalizationConfig::default())
+    }
+}
+
+impl Linter for HeadingCapitalization {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for heading in document.iter_headings() {
+            let Some(span) = heading.span() else {
+                continue;
+            };
+
+            let recased = self.recase_heading(heading, document.get_source());
+
+            if recased.as_slice() == document.get_span_content(span) {
+                continue;
+            }
+
+            lints.push(Lint {
+                span,
+                lint_kind: LintKind::Capitalization,
+                rule_id: "HeadingCapitalization".to_string(),
+                suggestions: vec![Suggestion::ReplaceWith(recased)],
+                message: format!(
+                    "This heading should be capitalized in {}.",
+                    self.config.style.name()
+                ),
+                priority: 63,
+            })
+        }
+
+        lints
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Heading Capitalization",
+            "Checks that headings are capitalized in a consistent style.",
+            LintKind::Capitalization,
+        )
+        .with_long_description(
+            "Headings can be written in sentence case, where only the first word and proper nouns are capitalized, or in title case, where most words are. This rule checks the headings of Markdown documents against the `style` option, which may be `SentenceCase` (the default), `ApTitleCase` or `ChicagoTitleCase`.",
+        )
+        .with_good_example("# Getting started with Harper")
+        .with_bad_example("# Getting Started With Harper")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeadingCapitalization, HeadingCapitalizationConfig, HeadingStyle};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    fn with_style(style: HeadingStyle) -> HeadingCapitalization {
+        HeadingCapitalization::new(HeadingCapitalizationConfig { style })
+    }
+
+    #[test]
+    fn sentence_case() {
+        assert_suggestion_result(
+            "# How To Configure The Language Server\n\nSome Text.",
+            HeadingCapitalization::default(),
+            "# How to configure the language server\n\nSome Text.",
+        );
+    }
+
+    #[test]
+    fn sentence_case_keeps_proper_nouns() {
+        assert_lint_count(
+            "## Installing Harper for English on macOS with the API",
+            HeadingCapitalization::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn sentence_case_capitalizes_first_word() {
+        assert_suggestion_result(
+            "# using the language server",
+            HeadingCapitalization::default(),
+            "# Using the language server",
+        );
+    }
+
+    #[test]
+    fn ap_title_case() {
+        assert_suggestion_result(
+            "# how to install the plugin with a package manager",
+            with_style(HeadingStyle::ApTitleCase),
+            "# How to Install the Plugin With a Package Manager",
+        );
+    }
+
+    #[test]
+    fn chicago_title_case() {
+        assert_suggestion_result(
+            "# How To Install The Plugin With A Package Manager",
+            with_style(HeadingStyle::ChicagoTitleCase),
+            "# How to Install the Plugin with a Package Manager",
+        );
+    }
+
+    #[test]
+    fn title_case_capitalizes_last_word_and_after_colon() {
+        assert_suggestion_result(
+            "# Harper: a guide to what it is for",
+            with_style(HeadingStyle::ChicagoTitleCase),
+            "# Harper: A Guide to What It Is For",
+        );
+    }
+
+    #[test]
+    fn ignores_paragraphs_and_list_items() {
+        assert_lint_count(
+            "This Is Not A Heading.\n\n- Neither Is This",
+            HeadingCapitalization::default(),
+            0,
+        );
+    }
+}
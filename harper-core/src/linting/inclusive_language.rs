@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+
+use super::phrase_rules::PhraseRules;
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
+use crate::{Document, TokenStringExt};
+
+const SLAVERY: &str = "This term is rooted in slavery, and there are more precise alternatives.";
+const RACIAL: &str = "This term associates white with good and black with bad.";
+const ABLEIST: &str = "This term treats mental illness or disability as a flaw.";
+const GENDERED: &str = "This term assumes that everyone is a man.";
+const LEGACY: &str = "This term comes from laws that kept Black Americans from voting.";
+
+/// The built-in terms, with their alternatives and an explanation of why
+/// they should be avoided.
+const TERMS: &[(&str, &[&str], &str)] = &[
+    (
+        "master/slave",
+        &["primary/replica", "leader/follower"],
+        SLAVERY,
+    ),
+    ("slave", &["replica", "follower", "secondary"], SLAVERY),
+    ("slaves", &["replicas", "followers", "secondaries"], SLAVERY),
+    ("master branch", &["main branch"], SLAVERY),
+    ("whitelist", &["allowlist"], RACIAL),
+    ("whitelists", &["allowlists"], RACIAL),
+    ("whitelisted", &["allowlisted"], RACIAL),
+    ("whitelisting", &["allowlisting"], RACIAL),
+    ("blacklist", &["denylist", "blocklist"], RACIAL),
+    ("blacklists", &["denylists", "blocklists"], RACIAL),
+    ("blacklisted", &["denylisted", "blocklisted"], RACIAL),
+    ("blacklisting", &["denylisting", "blocklisting"], RACIAL),
+    (
+        "sanity check",
+        &["quick check", "confidence check", "coherence check"],
+        ABLEIST,
+    ),
+    (
+        "sanity checks",
+        &["quick checks", "confidence checks", "coherence checks"],
+        ABLEIST,
+    ),
+    ("sanity-check", &["check", "verify"], ABLEIST),
+    ("crazy", &["surprising", "wild", "unexpected"], ABLEIST),
+    ("cripple", &["disable", "hinder"], ABLEIST),
+    ("crippled", &["disabled", "hindered"], ABLEIST),
+    (
+        "dummy value",
+        &["placeholder value", "sample value"],
+        ABLEIST,
+    ),
+    ("guys", &["everyone", "folks", "team"], GENDERED),
+    ("manpower", &["workforce", "staff"], GENDERED),
+    ("man-hours", &["person-hours", "work hours"], GENDERED),
+    ("grandfathered", &["legacy", "exempt"], LEGACY),
+];
+
+/// A term for the [`InclusiveLanguage`] linter to look for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusiveTerm {
+    /// The term to look for, which is matched regardless of case.
+    /// It may span several words.
+    pub term: String,
+    /// The terms to suggest in its place.
+    #[serde(default)]
+    pub alternatives: Vec<String>,
+    /// Why the term should be avoided.
+    /// If [`None`], a generic explanation is shown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+/// Options for the [`InclusiveLanguage`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct InclusiveLanguageConfig {
+    /// Terms to look for in addition to the built-in ones.
+    /// A term with the same text as a built-in one replaces it.
+    pub terms: Vec<InclusiveTerm>,
+    /// Built-in terms that should not be flagged.
+    pub allowed: Vec<String>,
+}
+
+/// What to say about a term from the list.
+struct TermAdvice {
+    alternatives: Vec<Vec<char>>,
+    explanation: String,
+}
+
+impl TermAdvice {
+    fn new(alternatives: &[impl AsRef<str>], explanation: &str) -> Self {
+        Self {
+            alternatives: alternatives
+                .iter()
+                .map(|alternative| alternative.as_ref().chars().collect())
+                .collect(),
+            explanation: explanation.to_string(),
+        }
+    }
+}
+
+/// Looks for terms that may exclude or offend readers, like “whitelist” or
+/// “guys”, and suggests more inclusive alternatives.
+pub struct InclusiveLanguage {
+    terms: PhraseRules<TermAdvice>,
+}
+
+impl InclusiveLanguage {
+    pub fn new(config: InclusiveLanguageConfig) -> Self {
+        let is_replaced = |term: &str| {
+            config
+                .allowed
+                .iter()
+                .chain(config.terms.iter().map(|t| &t.term))
+                .any(|other| other.trim().eq_ignore_ascii_case(term))
+        };
+
+        let mut terms = PhraseRules::default();
+
+        for (term, alternatives, explanation) in TERMS {
+            if !is_replaced(term) {
+                terms.push(term, false, TermAdvice::new(alternatives, explanation));
+            }
+        }
+
+        for t in &config.terms {
+            let explanation = t
+                .explanation
+                .as_deref()
+                .unwrap_or("This term may exclude or offend some readers.");
+
+            terms.push(
+                &t.term,
+                false,
+                TermAdvice::new(&t.alternatives, explanation),
+            );
+        }
+
+        Self { terms }
+    }
+}
+
+impl Default for InclusiveLanguage {
+    fn default() -> Self {
+        Self::new(InclusiveLanguageConfig::default())
+    }
+}
+
+impl Linter for InclusiveLanguage {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let tokens = document.get_tokens();
+
+        self.terms
+            .find_all(document)
+            .into_iter()
+            .map(|(found, advice)| {
+                let span = tokens[found].span().unwrap();
+                let content = document.get_span_content(span);
+
+                Lint {
+                    span,
+                    lint_kind: LintKind::Miscellaneous,
+                    rule_id: "InclusiveLanguage".to_string(),
+                    suggestions: advice
+                        .alternatives
+                        .iter()
+                        .map(|alternative| {
+                            Suggestion::replace_with_match_case(alternative.clone(), content)
+                        })
+                        .collect(),
+                    message: format!(
+                        "Consider avoiding “{}”. {}",
+                        document.get_span_content_str(span),
+                        advice.explanation
+                    ),
+                    priority: 63,
+                }
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Inclusive Language",
+            "Suggests inclusive alternatives to terms that may exclude or offend readers.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Some common terms, like “whitelist”, “master/slave” or “guys”, carry connotations that can alienate readers, and there is usually a clearer alternative.",
+        )
+        .with_good_example("Add the host to the allowlist, everyone.")
+        .with_bad_example("Add the host to the whitelist, guys.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InclusiveLanguage;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn suggests_alternatives() {
+        assert_suggestion_result(
+            "Blacklisted hosts are rejected.",
+            InclusiveLanguage::default(),
+            "Denylisted hosts are rejected.",
+        );
+    }
+
+    #[test]
+    fn matches_phrases() {
+        assert_suggestion_result(
+            "Set up master/slave replication after a sanity check.",
+            InclusiveLanguage::default(),
+            "Set up primary/replica replication after a quick check.",
+        );
+    }
+
+    #[test]
+    fn prefers_longer_terms() {
+        assert_lint_count(
+            "Set up master/slave replication.",
+            InclusiveLanguage::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn allows_unrelated_words() {
+        assert_lint_count(
+            "She mastered the guitar and holds a master's degree.",
+            InclusiveLanguage::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_removed_terms() {
+        assert_lint_count(
+            "Thanks, guys!",
+            InclusiveLanguage::new(serde_json::from_str(r#"{ "allowed": ["guys"] }"#).unwrap()),
+            0,
+        );
+    }
+
+    #[test]
+    fn uses_added_terms() {
+        assert_suggestion_result(
+            "Kill the process.",
+            InclusiveLanguage::new(
+                serde_json::from_str(
+                    r#"{ "terms": [{ "term": "kill", "alternatives": ["stop"] }] }"#,
+                )
+                .unwrap(),
+            ),
+            "Stop the process.",
+        );
+    }
+
+    #[test]
+    fn added_terms_replace_built_in_ones() {
+        assert_suggestion_result(
+            "Thanks, guys!",
+            InclusiveLanguage::new(
+                serde_json::from_str(
+                    r#"{ "terms": [{ "term": "guys", "alternatives": ["y'all"] }] }"#,
+                )
+                .unwrap(),
+            ),
+            "Thanks, y'all!",
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Linter, LinterDescription};
 use crate::token::TokenStringExt;
 use crate::Document;
 
@@ -11,16 +11,23 @@ impl Linter for LinkingVerbs {
         let mut output = Vec::new();
 
         for chunk in document.iter_chunks() {
-            // The word prior to "is" must be a noun.
+            // The word prior to "is" must be a noun. Both are judged by the
+            // part of speech they play in the sentence.
             for idx in chunk.iter_linking_verb_indices() {
                 let linking_verb = chunk[idx];
+
+                if !linking_verb.kind.as_word().unwrap().narrowed().is_verb() {
+                    continue;
+                }
+
                 let linking_verb_text = document.get_span_content_str(linking_verb.span);
 
                 if let Some(prev_word) = &chunk[0..idx].last_word() {
-                    if !prev_word.kind.as_word().unwrap().is_noun() {
+                    if !prev_word.kind.as_word().unwrap().narrowed().is_noun() {
                         output.push(Lint {
                             span: linking_verb.span,
                             lint_kind: LintKind::Miscellaneous,
+                            rule_id: "LinkingVerbs".to_string(),
                             message: format!(
                                 "Linking verbs like “{}” must be preceded by a noun.",
                                 linking_verb_text
@@ -34,6 +41,19 @@ impl Linter for LinkingVerbs {
 
         output
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Linking Verbs",
+            "Checks that linking verbs are preceded by a noun.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Linking verbs like “is” connect a subject to more information about it, so they should follow a noun.",
+        )
+        .with_good_example("Dora is a noun.")
+        .with_bad_example("working is not a noun.")
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +71,12 @@ mod tests {
         assert_lint_count("working is not a noun.", LinkingVerbs, 1);
     }
 
+    #[test]
+    fn allows_nouns_that_can_be_verbs() {
+        assert_lint_count("Our work is done.", LinkingVerbs, 0);
+        assert_lint_count("The present is a gift.", LinkingVerbs, 0);
+    }
+
     #[test]
     fn working_right() {
         assert_lint_count("\"working\" is a noun.", LinkingVerbs, 0);
@@ -9,6 +9,10 @@ use crate::Span;
 pub struct Lint {
     pub span: Span,
     pub lint_kind: LintKind,
+    /// A stable identifier for the rule that produced the lint.
+    /// For built-in linters, this is the name of the linter (i.e. `SpellCheck`).
+    #[serde(default)]
+    pub rule_id: String,
     pub suggestions: Vec<Suggestion>,
     pub message: String,
     /// A numerical value for the importance of a lint.
@@ -21,6 +25,7 @@ impl Default for Lint {
         Self {
             span: Default::default(),
             lint_kind: Default::default(),
+            rule_id: Default::default(),
             suggestions: Default::default(),
             message: Default::default(),
             priority: 127,
@@ -28,7 +33,7 @@ impl Default for Lint {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Is, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Is, Default)]
 pub enum LintKind {
     Spelling,
     Capitalization,
@@ -56,36 +61,78 @@ impl Display for LintKind {
     }
 }
 
+/// A change to a single region of text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replacement {
+    /// The characters to be replaced.
+    /// May be empty, in which case the text is inserted at its start.
+    pub span: Span,
+    pub text: Vec<char>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Is)]
 pub enum Suggestion {
     ReplaceWith(Vec<char>),
+    /// Insert text immediately before the span of the lint.
+    InsertBefore(Vec<char>),
+    /// Insert text immediately after the span of the lint.
+    InsertAfter(Vec<char>),
+    /// Make several changes at once.
+    /// The spans are relative to the whole document, not to the lint, and must
+    /// not overlap.
+    Edits(Vec<Replacement>),
     Remove,
 }
 
 impl Suggestion {
-    /// Apply a suggestion to a given text.
-    pub fn apply(&self, span: Span, source: &mut Vec<char>) {
+    /// Create a suggestion to replace text with a value, adjusted to match the
+    /// casing of the text it replaces.
+    ///
+    /// If the original is entirely uppercase (like `TEH`), so is the result.
+    /// If it only starts with an uppercase letter (like `Teh`), the first letter
+    /// of the result is capitalized.
+    /// Otherwise, the value is used as-is, so that the casing of proper nouns is
+    /// preserved.
+    pub fn replace_with_match_case(value: Vec<char>, template: &[char]) -> Self {
+        Self::ReplaceWith(match_case(value, template))
+    }
+
+    /// Get the concrete changes that applying the suggestion to a given span
+    /// would make, sorted by position.
+    pub fn replacements(&self, span: Span) -> Vec<Replacement> {
         match self {
-            Self::ReplaceWith(chars) => {
-                // Avoid allocation if possible
-                if chars.len() == span.len() {
-                    for (index, c) in chars.iter().enumerate() {
-                        source[index + span.start] = *c
-                    }
-                } else {
-                    let popped = source.split_off(span.start);
-
-                    source.extend(chars);
-                    source.extend(popped.into_iter().skip(span.len()));
-                }
+            Self::ReplaceWith(chars) => vec![Replacement {
+                span,
+                text: chars.clone(),
+            }],
+            Self::InsertBefore(chars) => vec![Replacement {
+                span: Span::new_with_len(span.start, 0),
+                text: chars.clone(),
+            }],
+            Self::InsertAfter(chars) => vec![Replacement {
+                span: Span::new_with_len(span.end, 0),
+                text: chars.clone(),
+            }],
+            Self::Edits(edits) => {
+                let mut edits = edits.clone();
+                edits.sort_by_key(|e| (e.span.start, e.span.end));
+                edits
             }
-            Self::Remove => {
-                for i in span.end..source.len() {
-                    source[i - span.len()] = source[i];
-                }
+            Self::Remove => vec![Replacement {
+                span,
+                text: Vec::new(),
+            }],
+        }
+    }
 
-                source.truncate(source.len() - span.len());
-            }
+    /// Apply a suggestion to a given text.
+    pub fn apply(&self, span: Span, source: &mut Vec<char>) {
+        // Work backwards so earlier spans remain valid.
+        for replacement in self.replacements(span).into_iter().rev() {
+            source.splice(
+                replacement.span.start..replacement.span.end,
+                replacement.text,
+            );
         }
     }
 }
@@ -96,7 +143,105 @@ impl Display for Suggestion {
             Suggestion::ReplaceWith(with) => {
                 write!(f, "Replace with: “{}”", with.iter().collect::<String>())
             }
+            Suggestion::InsertBefore(text) | Suggestion::InsertAfter(text) => {
+                write!(f, "Insert “{}”", text.iter().collect::<String>())
+            }
+            Suggestion::Edits(edits) => write!(f, "Apply {} edits", edits.len()),
             Suggestion::Remove => write!(f, "Remove error"),
         }
     }
 }
+
+/// Adjust the casing of a value to mirror a template.
+fn match_case(mut value: Vec<char>, template: &[char]) -> Vec<char> {
+    let mut letters = template.iter().filter(|c| c.is_alphabetic()).peekable();
+
+    let Some(first) = letters.peek().copied() else {
+        return value;
+    };
+
+    let all_upper = template.iter().filter(|c| c.is_alphabetic()).count() > 1
+        && letters.all(|c| c.is_uppercase());
+
+    if all_upper {
+        return value.into_iter().flat_map(|c| c.to_uppercase()).collect();
+    }
+
+    if first.is_uppercase() {
+        if let Some(idx) = value.iter().position(|c| c.is_alphabetic()) {
+            let upper: Vec<char> = value[idx].to_uppercase().collect();
+            value.splice(idx..=idx, upper);
+        }
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Replacement, Suggestion};
+    use crate::Span;
+
+    fn apply(suggestion: Suggestion, span: Span, text: &str) -> String {
+        let mut source: Vec<char> = text.chars().collect();
+        suggestion.apply(span, &mut source);
+        source.into_iter().collect()
+    }
+
+    fn replacement_text(suggestion: Suggestion) -> String {
+        match suggestion {
+            Suggestion::ReplaceWith(chars) => chars.into_iter().collect(),
+            _ => panic!("Expected a replacement."),
+        }
+    }
+
+    #[test]
+    fn inserts_before_and_after() {
+        let span = Span::new(4, 9);
+
+        assert_eq!(
+            apply(Suggestion::InsertBefore(vec!['“']), span, "Say hello."),
+            "Say “hello."
+        );
+        assert_eq!(
+            apply(Suggestion::InsertAfter(vec!['”']), span, "Say hello."),
+            "Say hello”."
+        );
+    }
+
+    #[test]
+    fn applies_multiple_edits() {
+        let suggestion = Suggestion::Edits(vec![
+            Replacement {
+                span: Span::new(9, 14),
+                text: "quick".chars().collect(),
+            },
+            Replacement {
+                span: Span::new(4, 8),
+                text: "brown".chars().collect(),
+            },
+        ]);
+
+        assert_eq!(
+            apply(suggestion, Span::new(4, 14), "the fast brown fox"),
+            "the brown quick fox"
+        );
+    }
+
+    #[test]
+    fn matches_case() {
+        let sug = |value: &str, template: &str| {
+            let template: Vec<char> = template.chars().collect();
+            replacement_text(Suggestion::replace_with_match_case(
+                value.chars().collect(),
+                &template,
+            ))
+        };
+
+        assert_eq!(sug("the", "teh"), "the");
+        assert_eq!(sug("the", "Teh"), "The");
+        assert_eq!(sug("the", "TEH"), "THE");
+        assert_eq!(sug("iPhone", "iphon"), "iPhone");
+        assert_eq!(sug("a", "A"), "A");
+    }
+}
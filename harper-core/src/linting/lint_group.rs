@@ -1,127 +1,600 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
 use paste::paste;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use super::an_a::AnA;
 use super::avoid_curses::AvoidCurses;
-use super::boring_words::BoringWords;
+use super::boring_words::{BoringWords, BoringWordsConfig};
 use super::capitalize_personal_pronouns::CapitalizePersonalPronouns;
+use super::confusables::Confusables;
 use super::correct_number_suffix::CorrectNumberSuffix;
+use super::dialect_spelling::DialectSpelling;
 use super::dot_initialisms::DotInitialisms;
 use super::ellipsis_length::EllipsisLength;
+use super::heading_capitalization::{HeadingCapitalization, HeadingCapitalizationConfig};
+use super::inclusive_language::{InclusiveLanguage, InclusiveLanguageConfig};
 use super::linking_verbs::LinkingVerbs;
-use super::long_sentences::LongSentences;
-use super::matcher::Matcher;
+use super::long_sentences::{LongSentences, LongSentencesConfig};
+use super::matcher::{Matcher, MatcherConfig};
 use super::multiple_sequential_pronouns::MultipleSequentialPronouns;
 use super::number_suffix_capitalization::NumberSuffixCapitalization;
+use super::paragraph_readability::{ParagraphReadability, ParagraphReadabilityConfig};
+use super::passive_voice::PassiveVoice;
 use super::repeated_words::RepeatedWords;
 use super::sentence_capitalization::SentenceCapitalization;
 use super::spaces::Spaces;
-use super::spell_check::SpellCheck;
+use super::spell_check::{SpellCheck, SpellCheckConfig};
 use super::spelled_numbers::SpelledNumbers;
+use super::style_preset::StylePreset;
+use super::subject_verb_agreement::SubjectVerbAgreement;
 use super::terminating_conjunctions::TerminatingConjunctions;
+use super::terminology::{Terminology, TerminologyConfig};
 use super::that_which::ThatWhich;
 use super::unclosed_quotes::UnclosedQuotes;
+use super::undefined_acronyms::{UndefinedAcronyms, UndefinedAcronymsConfig};
 use super::use_genitive::UseGenitive;
+use super::wordiness::{Wordiness, WordinessConfig};
 use super::wrong_quotes::WrongQuotes;
-use super::{Lint, Linter};
-use crate::{Dictionary, Document};
+use super::{Lint, Linter, LinterDescription};
+use crate::suppression::rule_names_match;
+use crate::{Dialect, Dictionary, Document};
+
+/// The configuration of a linter that accepts options.
+///
+/// For backwards compatibility, this can be deserialized from a plain boolean,
+/// which toggles the linter while leaving its options at their defaults.
+///
+/// ```json
+/// { "long_sentences": true }
+/// { "long_sentences": { "enabled": true, "max_words": 30 } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LinterSetting<C> {
+    Toggle(bool),
+    Configured {
+        /// If set to [`None`], the default for the linter will be used.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        enabled: Option<bool>,
+        #[serde(flatten)]
+        options: C,
+    },
+}
+
+impl<C: Clone + Default> LinterSetting<C> {
+    /// Get the configured options, falling back to the defaults if the setting
+    /// is a plain toggle.
+    pub fn options(&self) -> C {
+        match self {
+            Self::Toggle(_) => C::default(),
+            Self::Configured { options, .. } => options.clone(),
+        }
+    }
+}
+
+impl<C: Default> LinterSetting<C> {
+    /// Get a mutable reference to the options, converting a plain toggle into
+    /// a configured setting if necessary.
+    pub fn options_mut(&mut self) -> &mut C {
+        if let Self::Toggle(enabled) = self {
+            *self = Self::Configured {
+                enabled: Some(*enabled),
+                options: C::default(),
+            };
+        }
+
+        match self {
+            Self::Configured { options, .. } => options,
+            Self::Toggle(_) => unreachable!(),
+        }
+    }
+}
+
+impl<C: Default> Default for LinterSetting<C> {
+    fn default() -> Self {
+        Self::Configured {
+            enabled: None,
+            options: C::default(),
+        }
+    }
+}
+
+impl<C> From<bool> for LinterSetting<C> {
+    fn from(value: bool) -> Self {
+        Self::Toggle(value)
+    }
+}
+
+/// Common behavior for the values of each field in a [`LintGroupConfig`].
+trait Setting {
+    /// Whether the linter is enabled, if known.
+    fn enabled(&self) -> Option<bool>;
+    /// Fill in whether the linter is enabled, if not already known.
+    fn fill_enabled(&mut self, default: bool);
+    /// Fill in whatever isn't known from a setting with lower precedence.
+    fn layer_over(&mut self, base: &Self);
+}
+
+impl Setting for bool {
+    fn enabled(&self) -> Option<bool> {
+        Some(*self)
+    }
+
+    fn fill_enabled(&mut self, _default: bool) {}
+
+    fn layer_over(&mut self, _base: &Self) {}
+}
+
+impl<C: Clone + Default + Serialize + DeserializeOwned> Setting for LinterSetting<C> {
+    fn enabled(&self) -> Option<bool> {
+        match self {
+            Self::Toggle(enabled) => Some(*enabled),
+            Self::Configured { enabled, .. } => *enabled,
+        }
+    }
+
+    fn fill_enabled(&mut self, default: bool) {
+        if let Self::Configured { enabled, .. } = self {
+            enabled.get_or_insert(default);
+        }
+    }
+
+    fn layer_over(&mut self, base: &Self) {
+        match (&mut *self, base) {
+            // A plain toggle keeps the options of the setting below it.
+            (Self::Toggle(enabled), Self::Configured { options, .. }) => {
+                *self = Self::Configured {
+                    enabled: Some(*enabled),
+                    options: options.clone(),
+                }
+            }
+            (Self::Configured { enabled, options }, base) => {
+                if enabled.is_none() {
+                    *enabled = base.enabled();
+                }
+
+                if let Self::Configured {
+                    options: base_options,
+                    ..
+                } = base
+                {
+                    *options = layer_options(options, base_options);
+                }
+            }
+            (Self::Toggle(_), Self::Toggle(_)) => (),
+        }
+    }
+}
+
+/// Layer linter options over those of a setting with lower precedence, field
+/// by field.
+///
+/// Fields that are left at their defaults are taken from `base`.
+fn layer_options<C: Clone + Default + Serialize + DeserializeOwned>(options: &C, base: &C) -> C {
+    let (Ok(serde_json::Value::Object(fields)), Ok(serde_json::Value::Object(mut layered))) =
+        (serde_json::to_value(options), serde_json::to_value(base))
+    else {
+        return options.clone();
+    };
+
+    let defaults = serde_json::to_value(C::default()).unwrap_or_default();
+
+    for (key, value) in fields {
+        if defaults.get(&key) != Some(&value) {
+            layered.insert(key, value);
+        }
+    }
+
+    serde_json::from_value(serde_json::Value::Object(layered)).unwrap_or_else(|_| options.clone())
+}
+
+/// Resolves to the type of a [`LintGroupConfig`] field, depending on whether the
+/// linter accepts options.
+macro_rules! setting_type {
+    () => {
+        bool
+    };
+    ($config:ty) => {
+        LinterSetting<$config>
+    };
+}
+
+/// Read the settings of custom linters, skipping any that don't have the
+/// shape of a [`LinterSetting`].
+fn deserialize_custom<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, LinterSetting<serde_json::Value>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let entries: HashMap<String, serde_json::Value> = HashMap::deserialize(deserializer)?;
+
+    Ok(entries
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, serde_json::from_value(value).ok()?)))
+        .collect())
+}
+
+/// Information about a linter registered with a [`LintGroup`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinterMetadata {
+    /// The stable identifier of the rule (i.e. `SpellCheck`).
+    /// Lints produced by the linter are marked with it.
+    pub rule_id: String,
+    /// Whether the linter runs when the [`LintGroupConfig`] does not say otherwise.
+    pub default_enabled: bool,
+    #[serde(flatten)]
+    pub description: LinterDescription,
+}
+
+impl LinterMetadata {
+    fn new(rule_id: String, default_enabled: bool, linter: &dyn Linter) -> Self {
+        let mut description = linter.describe();
+
+        if description.name.is_empty() {
+            description.name = humanize_rule_id(&rule_id);
+        }
+
+        Self {
+            rule_id,
+            default_enabled,
+            description,
+        }
+    }
+}
+
+/// Turn a rule id like `SpellCheck` into a name like `Spell Check`.
+fn humanize_rule_id(rule_id: &str) -> String {
+    let mut name = String::new();
+    let mut prev: Option<char> = None;
+
+    for c in rule_id.chars() {
+        if c == '_' || c == '-' {
+            name.push(' ');
+        } else if c.is_uppercase() && prev.is_some_and(|p| p.is_lowercase()) {
+            name.push(' ');
+            name.push(c);
+        } else {
+            name.push(c);
+        }
+
+        prev = Some(c);
+    }
+
+    name
+}
+
+struct RegisteredLinter {
+    metadata: LinterMetadata,
+    linter: Box<dyn Linter>,
+    /// Whether the linter was registered by Harper itself (as opposed to an
+    /// embedder), in which case it will be rebuilt when its options change.
+    builtin: bool,
+}
 
 macro_rules! create_lint_group_config {
-    ($($linter:ident => $default:expr),*) => {
+    ($($linter:ident $(($config:ty))? => $default:expr),*) => {
         paste! {
-            #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+            /// The settings of each linter in a [`LintGroup`].
+            ///
+            /// Since linter options can hold lists, like the words
+            /// [`BoringWords`] looks for, the config is [`Clone`] but not `Copy`.
+            #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
             pub struct LintGroupConfig {
                 $(
                     #[doc = "Configures the use of the [`" $linter "`] linter.
                     If set to [`None`], the default configuration will be used."]
-                    pub [<$linter:snake>]: Option<bool>,
+                    pub [<$linter:snake>]: Option<setting_type!($($config)?)>,
                 )*
-                pub spell_check: Option<bool>
+                pub spell_check: Option<LinterSetting<SpellCheckConfig>>,
+                /// The dialect of English to check spelling against.
+                /// If set to [`None`], the spellings of every dialect are accepted.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub dialect: Option<Dialect>,
+                /// A style guide to take settings from.
+                /// Any other settings in this config take precedence over it.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub preset: Option<StylePreset>,
+                /// Settings for linters registered at runtime with
+                /// [`LintGroup::register`], keyed by rule id.
+                /// Any options are passed to the linter's [`Linter::configure`].
+                ///
+                /// Entries that are neither a boolean nor an object are ignored,
+                /// so unknown keys don't prevent the rest of the config from loading.
+                #[serde(flatten, deserialize_with = "deserialize_custom")]
+                pub custom: HashMap<String, LinterSetting<serde_json::Value>>,
             }
 
             impl LintGroupConfig {
-                /// Creates a config with all lints disabled.
+                /// Creates a config with all built-in lints disabled.
                 pub fn none() -> Self{
                     Self {
                         $(
-                            [<$linter:snake>]: Some(false),
+                            [<$linter:snake>]: Some(false.into()),
                         )*
-                        spell_check: Some(false)
+                        spell_check: Some(false.into()),
+                        dialect: None,
+                        preset: None,
+                        custom: HashMap::new(),
                     }
                 }
 
-                /// Fills the [`None`] values in the configuration with the default values.
+                /// Fills the [`None`] values in the configuration with those
+                /// from the preset, if any, and then with the default values.
                 pub fn fill_default_values(&mut self){
+                    if let Some(preset) = self.preset {
+                        self.layer_over(&preset.config());
+                    }
+
+                    $(
+                        self.[<$linter:snake>]
+                            .get_or_insert_with(|| $default.into())
+                            .fill_enabled($default);
+                    )*
+
+                    self.spell_check
+                        .get_or_insert_with(|| true.into())
+                        .fill_enabled(true);
+                }
+
+                /// Fill in the settings this config doesn't specify from one
+                /// with lower precedence, like a [`StylePreset`].
+                pub fn layer_over(&mut self, base: &LintGroupConfig) {
                     $(
-                        if self.[<$linter:snake>].is_none() {
-                            self.[<$linter:snake>] = Some($default);
+                        match (&mut self.[<$linter:snake>], &base.[<$linter:snake>]) {
+                            (Some(setting), Some(base)) => setting.layer_over(base),
+                            (setting @ None, base) => *setting = base.clone(),
+                            (Some(_), None) => (),
                         }
                     )*
 
-                    if self.spell_check.is_none() {
-                        self.spell_check = Some(true);
+                    match (&mut self.spell_check, &base.spell_check) {
+                        (Some(setting), Some(base)) => setting.layer_over(base),
+                        (setting @ None, base) => *setting = base.clone(),
+                        (Some(_), None) => (),
                     }
-                }
-            }
 
-            /// A wrapper that combines all built-in Harper linters
-            /// into a single, configurable [`Linter`].
-            pub struct LintGroup<T: Dictionary> {
-                $(
-                    [<$linter:snake>]: $linter,
-                )*
-                spell_check: SpellCheck<T>,
-                pub config: LintGroupConfig
-            }
+                    if self.dialect.is_none() {
+                        self.dialect = base.dialect;
+                    }
 
+                    for (rule_id, base) in &base.custom {
+                        match self.custom.get_mut(rule_id) {
+                            Some(setting) => setting.layer_over(base),
+                            None => {
+                                self.custom.insert(rule_id.clone(), base.clone());
+                            }
+                        }
+                    }
+                }
 
-            impl<T: Dictionary> LintGroup<T> {
-                pub fn new(config: LintGroupConfig, dictionary: T) -> Self {
-                    Self {
+                /// Whether the config enables or disables a rule, if it says.
+                ///
+                /// Custom rules may be keyed by their rule id or its snake_case form.
+                pub fn is_enabled(&self, rule_id: &str) -> Option<bool> {
+                    match rule_id {
                         $(
-                            [<$linter:snake>]: $linter::default(),
+                            stringify!($linter) => self.[<$linter:snake>].as_ref().and_then(Setting::enabled),
                         )*
-                        spell_check: SpellCheck::new(dictionary),
-                        config,
+                        "SpellCheck" => self.spell_check.as_ref().and_then(Setting::enabled),
+                        _ => self
+                            .custom
+                            .iter()
+                            .find(|(key, _)| rule_names_match(key, rule_id))
+                            .and_then(|(_, setting)| setting.enabled()),
                     }
                 }
             }
 
-            impl<T: Dictionary> Linter for LintGroup<T> {
-                fn lint(&mut self, document: &Document) -> Vec<Lint>{
-                    let mut lints = Vec::new();
-
-                    let mut config = self.config.clone();
-                    config.fill_default_values();
+            impl<T: Dictionary + Clone + 'static> LintGroup<T> {
+                pub fn new(config: LintGroupConfig, dictionary: T) -> Self {
+                    let mut group = Self {
+                        linters: Vec::new(),
+                        dictionary: dictionary.clone(),
+                        config,
+                        applied_config: None,
+                    };
 
                     $(
-                        if config.[<$linter:snake>].unwrap() {
-                            lints.append(&mut self.[<$linter:snake>].lint(document));
-                        }
+                        group.insert(stringify!($linter), $default, Box::new($linter::default()), true);
                     )*
 
-                    if config.spell_check.unwrap() {
-                        lints.append(&mut self.spell_check.lint(document));
-                    }
+                    group.insert("SpellCheck", true, Box::new(SpellCheck::new(dictionary)), true);
 
+                    group
+                }
 
-                    lints
+                /// Push the options in a (filled) config down to the built-in
+                /// linters that accept them.
+                fn apply_options(&mut self, config: &LintGroupConfig) {
+                    $(
+                        $(
+                            let options: $config = config.[<$linter:snake>].as_ref().unwrap().options();
+                            self.replace_builtin(stringify!($linter), $linter::new(options));
+                        )?
+                    )*
+
+                    let mut spell_check = SpellCheck::new(self.dictionary.clone());
+                    spell_check.set_config(config.spell_check.as_ref().unwrap().options());
+                    self.replace_builtin("SpellCheck", spell_check);
+
+                    let dialect_spelling = match config.dialect {
+                        Some(dialect) => {
+                            DialectSpelling::new(dialect, Arc::new(self.dictionary.clone()))
+                        }
+                        None => DialectSpelling::default(),
+                    };
+                    self.replace_builtin("DialectSpelling", dialect_spelling);
                 }
             }
         }
     };
 }
 
+/// A wrapper that combines many linters into a single, configurable
+/// [`Linter`].
+///
+/// All of Harper's built-in linters are registered by default.
+/// Embedders can add their own with [`Self::register`].
+pub struct LintGroup<T: Dictionary> {
+    linters: Vec<RegisteredLinter>,
+    dictionary: T,
+    pub config: LintGroupConfig,
+    /// The configuration the linters' options were last set from.
+    applied_config: Option<LintGroupConfig>,
+}
+
+impl<T: Dictionary + Clone + 'static> LintGroup<T> {
+    /// Register a linter under a rule id.
+    ///
+    /// If a linter was already registered with the same rule id, it is
+    /// replaced and returned.
+    pub fn register(
+        &mut self,
+        rule_id: impl ToString,
+        default_enabled: bool,
+        linter: impl Linter + 'static,
+    ) -> Option<Box<dyn Linter>> {
+        // Make sure the new linter receives its options.
+        self.applied_config = None;
+        self.insert(rule_id, default_enabled, Box::new(linter), false)
+    }
+
+    /// Remove the linter registered under a rule id.
+    pub fn unregister(&mut self, rule_id: &str) -> Option<Box<dyn Linter>> {
+        let index = self.position(rule_id)?;
+        Some(self.linters.remove(index).linter)
+    }
+
+    /// Whether a linter has been registered under a rule id.
+    pub fn contains(&self, rule_id: &str) -> bool {
+        self.position(rule_id).is_some()
+    }
+
+    /// Get the metadata of the linter registered under a rule id.
+    pub fn get_metadata(&self, rule_id: &str) -> Option<&LinterMetadata> {
+        self.position(rule_id).map(|i| &self.linters[i].metadata)
+    }
+
+    /// Iterate over the metadata of every registered linter, in the order they
+    /// were registered.
+    pub fn metadata(&self) -> impl Iterator<Item = &LinterMetadata> {
+        self.linters.iter().map(|l| &l.metadata)
+    }
+
+    /// Pass the options in a (filled) config to the linters registered by
+    /// embedders.
+    fn configure_registered(&mut self, config: &LintGroupConfig) {
+        for entry in self.linters.iter_mut().filter(|l| !l.builtin) {
+            let options = config
+                .custom
+                .iter()
+                .find(|(key, _)| rule_names_match(key, &entry.metadata.rule_id))
+                .and_then(|(_, setting)| match setting {
+                    LinterSetting::Configured { options, .. } => Some(options.clone()),
+                    LinterSetting::Toggle(_) => None,
+                })
+                .unwrap_or_else(|| serde_json::Value::Object(Default::default()));
+
+            entry.linter.configure(&options);
+        }
+    }
+
+    /// Replace a built-in linter, unless an embedder has taken its place.
+    fn replace_builtin(&mut self, rule_id: &str, linter: impl Linter + 'static) {
+        if let Some(index) = self.position(rule_id) {
+            if self.linters[index].builtin {
+                self.linters[index].linter = Box::new(linter);
+            }
+        }
+    }
+
+    fn insert(
+        &mut self,
+        rule_id: impl ToString,
+        default_enabled: bool,
+        linter: Box<dyn Linter>,
+        builtin: bool,
+    ) -> Option<Box<dyn Linter>> {
+        let entry = RegisteredLinter {
+            metadata: LinterMetadata::new(rule_id.to_string(), default_enabled, linter.as_ref()),
+            linter,
+            builtin,
+        };
+
+        match self.position(&entry.metadata.rule_id) {
+            Some(index) => Some(std::mem::replace(&mut self.linters[index], entry).linter),
+            None => {
+                self.linters.push(entry);
+                None
+            }
+        }
+    }
+
+    /// Rule ids are compared like in suppression directives, so `an_a` finds
+    /// `AnA`.
+    fn position(&self, rule_id: &str) -> Option<usize> {
+        self.linters
+            .iter()
+            .position(|l| rule_names_match(&l.metadata.rule_id, rule_id))
+    }
+}
+
+impl<T: Dictionary + Clone + 'static> Linter for LintGroup<T> {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        let mut config = self.config.clone();
+        config.fill_default_values();
+
+        if self.applied_config.as_ref() != Some(&config) {
+            self.apply_options(&config);
+            self.configure_registered(&config);
+            self.applied_config = Some(config.clone());
+        }
+
+        for entry in &mut self.linters {
+            let enabled = config
+                .is_enabled(&entry.metadata.rule_id)
+                .unwrap_or(entry.metadata.default_enabled);
+
+            if enabled {
+                let mut linter_lints = entry.linter.lint(document);
+                attribute_lints(&mut linter_lints, &entry.metadata.rule_id, document);
+                lints.append(&mut linter_lints);
+            }
+        }
+
+        lints
+    }
+}
+
+/// Marks lints with the rule that produced them (if the linter did not already
+/// do so) and drops those that have been suppressed by the document.
+fn attribute_lints(lints: &mut Vec<Lint>, rule_id: &str, document: &Document) {
+    for lint in lints.iter_mut() {
+        if lint.rule_id.is_empty() {
+            lint.rule_id = rule_id.to_string();
+        }
+    }
+
+    let suppressions = document.suppressions();
+    lints.retain(|l| !suppressions.is_suppressed(l.span, rule_id));
+}
+
 create_lint_group_config!(
     SpelledNumbers => false,
     AnA => true,
     SentenceCapitalization => false,
     UnclosedQuotes => true,
     WrongQuotes => false,
-    LongSentences => true,
+    LongSentences(LongSentencesConfig) => true,
     RepeatedWords => true,
     Spaces => false,
-    Matcher => true,
+    Matcher(MatcherConfig) => true,
     CorrectNumberSuffix => true,
     NumberSuffixCapitalization => true,
     MultipleSequentialPronouns => true,
@@ -130,14 +603,320 @@ create_lint_group_config!(
     TerminatingConjunctions => true,
     EllipsisLength => true,
     DotInitialisms => true,
-    BoringWords => false,
+    BoringWords(BoringWordsConfig) => false,
     UseGenitive => false,
     ThatWhich => true,
-    CapitalizePersonalPronouns => true
+    CapitalizePersonalPronouns => true,
+    PassiveVoice => false,
+    ParagraphReadability(ParagraphReadabilityConfig) => false,
+    SubjectVerbAgreement => false,
+    Confusables => true,
+    DialectSpelling => true,
+    HeadingCapitalization(HeadingCapitalizationConfig) => false,
+    Wordiness(WordinessConfig) => false,
+    InclusiveLanguage(InclusiveLanguageConfig) => false,
+    Terminology(TerminologyConfig) => true,
+    UndefinedAcronyms(UndefinedAcronymsConfig) => false
 );
 
-impl<T: Dictionary + Default> Default for LintGroup<T> {
+impl<T: Dictionary + Clone + Default + 'static> Default for LintGroup<T> {
     fn default() -> Self {
         Self::new(LintGroupConfig::default(), T::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LintGroup, LintGroupConfig};
+    use crate::linting::{Lint, Linter};
+    use crate::{Dialect, Document, FstDictionary, TokenStringExt};
+
+    /// Flags every word.
+    struct EveryWord;
+
+    impl Linter for EveryWord {
+        fn lint(&mut self, document: &Document) -> Vec<Lint> {
+            document
+                .iter_words()
+                .map(|word| Lint {
+                    span: word.span,
+                    ..Default::default()
+                })
+                .collect()
+        }
+    }
+
+    /// Flags words with at least `min_length` letters.
+    #[derive(Default)]
+    struct LongWords {
+        min_length: usize,
+    }
+
+    impl Linter for LongWords {
+        fn lint(&mut self, document: &Document) -> Vec<Lint> {
+            document
+                .iter_words()
+                .filter(|word| word.span.len() >= self.min_length)
+                .map(|word| Lint {
+                    span: word.span,
+                    ..Default::default()
+                })
+                .collect()
+        }
+
+        fn configure(&mut self, options: &serde_json::Value) {
+            self.min_length = options["min_length"].as_u64().unwrap_or(0) as usize;
+        }
+    }
+
+    fn lint_count(document: &Document) -> usize {
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        linter.lint(document).len()
+    }
+
+    #[test]
+    fn markdown_comment_ignores_next_line() {
+        let doc = Document::new_markdown_curated(
+            "<!-- harper-ignore-next-line -->\nThis is an test.\n\nThis is an test.",
+        );
+
+        assert_eq!(lint_count(&doc), 1);
+    }
+
+    #[test]
+    fn plain_english_disables_named_rule() {
+        let doc = Document::new_plain_english_curated(
+            "harper-disable an_a\nThis is an test.\nharper-enable an_a\nThis is an test.",
+        );
+
+        assert_eq!(lint_count(&doc), 1);
+    }
+
+    #[test]
+    fn attributes_rule_ids() {
+        let doc = Document::new_plain_english_curated("This is an test of thiss.");
+
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        let mut rule_ids: Vec<_> = linter.lint(&doc).into_iter().map(|l| l.rule_id).collect();
+        rule_ids.sort();
+
+        assert_eq!(rule_ids, vec!["AnA", "SpellCheck"]);
+    }
+
+    #[test]
+    fn reports_confused_words_once() {
+        let doc = Document::new_plain_english_curated("There is more then one way.");
+
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        let rule_ids: Vec<_> = linter.lint(&doc).into_iter().map(|l| l.rule_id).collect();
+
+        assert_eq!(rule_ids, vec!["Confusables"]);
+    }
+
+    #[test]
+    fn accepts_plain_booleans() {
+        let config: LintGroupConfig =
+            serde_json::from_str(r#"{ "long_sentences": false, "spell_check": true }"#).unwrap();
+
+        assert_eq!(config.long_sentences, Some(false.into()));
+        assert_eq!(config.spell_check, Some(true.into()));
+    }
+
+    #[test]
+    fn applies_linter_options() {
+        let config: LintGroupConfig = serde_json::from_str(
+            r#"{ "long_sentences": { "max_words": 3 }, "spell_check": false }"#,
+        )
+        .unwrap();
+
+        let doc = Document::new_plain_english_curated("This sentence is short enough.");
+        let mut linter = LintGroup::new(config, FstDictionary::curated());
+
+        assert_eq!(linter.lint(&doc).len(), 1);
+
+        linter.config.long_sentences = Some(false.into());
+        assert_eq!(linter.lint(&doc).len(), 0);
+    }
+
+    #[test]
+    fn applies_dialect() {
+        let config: LintGroupConfig = serde_json::from_str(r#"{ "dialect": "british" }"#).unwrap();
+
+        let doc = Document::new_plain_english_curated("My favorite color is gray.");
+        let mut linter = LintGroup::new(config, FstDictionary::curated());
+
+        assert_eq!(linter.lint(&doc).len(), 3);
+
+        linter.config.dialect = None;
+        assert!(linter.lint(&doc).is_empty());
+    }
+
+    #[test]
+    fn accepts_any_dialect_by_default() {
+        let doc = Document::new_plain_english_curated("My favourite colour is grey.");
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+
+        assert!(linter.lint(&doc).is_empty());
+    }
+
+    #[test]
+    fn accepts_dialect_names_and_codes() {
+        for name in ["british", "British", "en-GB", "uk"] {
+            let config: LintGroupConfig =
+                serde_json::from_str(&format!(r#"{{ "dialect": "{name}" }}"#)).unwrap();
+
+            assert_eq!(config.dialect, Some(Dialect::British));
+        }
+
+        let config = LintGroupConfig {
+            dialect: Some(Dialect::Canadian),
+            ..Default::default()
+        };
+        let round_trip: LintGroupConfig =
+            serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+
+        assert_eq!(round_trip.dialect, Some(Dialect::Canadian));
+        assert!(serde_json::from_str::<LintGroupConfig>(r#"{ "dialect": "martian" }"#).is_err());
+    }
+
+    #[test]
+    fn registers_builtins() {
+        let linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+
+        assert!(linter.contains("SpellCheck"));
+
+        let an_a = linter.get_metadata("an_a").unwrap();
+        assert_eq!(an_a.rule_id, "AnA");
+        assert!(an_a.default_enabled);
+    }
+
+    #[test]
+    fn builtin_examples_are_accurate() {
+        // Dialect Spelling's examples are written in American English.
+        let config = LintGroupConfig {
+            dialect: Some(Dialect::American),
+            ..Default::default()
+        };
+        let mut group = LintGroup::new(config, FstDictionary::curated());
+        group.lint(&Document::default());
+
+        for entry in &mut group.linters {
+            let description = &entry.metadata.description;
+            assert!(
+                !description.summary.is_empty(),
+                "{}",
+                entry.metadata.rule_id
+            );
+
+            for good in &description.good_examples {
+                let doc = Document::new_markdown_curated(good);
+                assert_eq!(
+                    entry.linter.lint(&doc).len(),
+                    0,
+                    "{}: {good}",
+                    entry.metadata.rule_id
+                );
+            }
+
+            for bad in &description.bad_examples {
+                let doc = Document::new_markdown_curated(bad);
+                assert_ne!(
+                    entry.linter.lint(&doc).len(),
+                    0,
+                    "{}: {bad}",
+                    entry.metadata.rule_id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn runs_custom_linter() {
+        let doc = Document::new_plain_english_curated("Hello there");
+
+        let mut linter = LintGroup::new(LintGroupConfig::none(), FstDictionary::curated());
+        linter.register("EveryWord", true, EveryWord);
+
+        let lints = linter.lint(&doc);
+
+        assert_eq!(lints.len(), 2);
+        assert!(lints.iter().all(|l| l.rule_id == "EveryWord"));
+    }
+
+    #[test]
+    fn configures_custom_linter() {
+        let doc = Document::new_plain_english_curated("Hello there");
+
+        let mut config: LintGroupConfig =
+            serde_json::from_str(r#"{ "every_word": { "enabled": false } }"#).unwrap();
+        config.spell_check = Some(false.into());
+
+        let mut linter = LintGroup::new(config, FstDictionary::curated());
+        linter.register("EveryWord", true, EveryWord);
+
+        assert_eq!(linter.lint(&doc).len(), 0);
+
+        linter.unregister("EveryWord");
+        linter.config.custom.clear();
+
+        assert_eq!(linter.lint(&doc).len(), 0);
+    }
+
+    #[test]
+    fn passes_options_to_custom_linter() {
+        let doc = Document::new_plain_english_curated("Hello there, you");
+
+        let mut config: LintGroupConfig =
+            serde_json::from_str(r#"{ "long_words": { "min_length": 4 } }"#).unwrap();
+        config.spell_check = Some(false.into());
+
+        let mut linter = LintGroup::new(config, FstDictionary::curated());
+        linter.register("LongWords", true, LongWords::default());
+
+        assert_eq!(linter.lint(&doc).len(), 2);
+
+        linter.config.custom.clear();
+        assert_eq!(linter.lint(&doc).len(), 3);
+    }
+
+    #[test]
+    fn accepts_any_custom_setting() {
+        let config: LintGroupConfig = serde_json::from_str(
+            r#"{ "foo": 3, "my_rule": { "enabled": true, "level": 2 }, "other_rule": false }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.is_enabled("MyRule"), Some(true));
+        assert_eq!(config.is_enabled("OtherRule"), Some(false));
+        assert_eq!(config.is_enabled("Foo"), None);
+
+        let json = serde_json::to_string(&config).unwrap();
+        let round_trip: LintGroupConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(round_trip, config);
+    }
+
+    #[test]
+    fn keeps_replaced_builtin() {
+        let doc = Document::new_plain_english_curated("Hello there");
+
+        let config = LintGroupConfig {
+            long_sentences: Some(true.into()),
+            ..LintGroupConfig::none()
+        };
+
+        let mut linter = LintGroup::new(config, FstDictionary::curated());
+        linter.register("LongSentences", true, EveryWord);
+
+        assert_eq!(linter.lint(&doc).len(), 2);
+    }
+
+    #[test]
+    fn disabling_other_rule_has_no_effect() {
+        let doc = Document::new_plain_english_curated(
+            "harper-disable spell_check\nThis is an test.\nharper-enable\n",
+        );
+
+        assert_eq!(lint_count(&doc), 1);
+    }
+}
@@ -1,10 +1,34 @@
-use super::{Lint, LintKind, Linter};
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, LinterDescription};
 use crate::token::TokenStringExt;
 use crate::{Document, Span};
 
+/// Options for the [`LongSentences`] linter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LongSentencesConfig {
+    /// The number of words a sentence may have before it is flagged.
+    pub max_words: usize,
+}
+
+impl Default for LongSentencesConfig {
+    fn default() -> Self {
+        Self { max_words: 40 }
+    }
+}
+
 /// Detect and warn that the sentence is too long.
 #[derive(Debug, Clone, Copy, Default)]
-pub struct LongSentences;
+pub struct LongSentences {
+    config: LongSentencesConfig,
+}
+
+impl LongSentences {
+    pub fn new(config: LongSentencesConfig) -> Self {
+        Self { config }
+    }
+}
 
 impl Linter for LongSentences {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
@@ -13,10 +37,11 @@ impl Linter for LongSentences {
         for sentence in document.iter_sentences() {
             let word_count = sentence.iter_words().count();
 
-            if word_count > 40 {
+            if word_count > self.config.max_words {
                 output.push(Lint {
                     span: Span::new(sentence[0].span.start, sentence.last().unwrap().span.end),
                     lint_kind: LintKind::Readability,
+                    rule_id: "LongSentences".to_string(),
                     message: format!("This sentence is {} words long.", word_count),
                     ..Default::default()
                 })
@@ -25,4 +50,41 @@ impl Linter for LongSentences {
 
         output
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Long Sentences",
+            "Looks for sentences that are too long to read comfortably.",
+            LintKind::Readability,
+        )
+        .with_long_description(
+            "Long sentences are harder to follow, since the reader must keep more in mind before reaching the end. Consider splitting them into several shorter ones.",
+        )
+        .with_good_example("This sentence is short and easy to read.")
+        .with_bad_example("This sentence goes on and on and on, adding clause after clause and idea after idea, never stopping to let the reader catch their breath, until it has become so long that nobody could possibly remember how it began or what it was trying to say.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LongSentences, LongSentencesConfig};
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn allows_short_sentences() {
+        assert_lint_count(
+            "This sentence is short enough.",
+            LongSentences::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn respects_max_words() {
+        assert_lint_count(
+            "This sentence is short enough.",
+            LongSentences::new(LongSentencesConfig { max_words: 3 }),
+            1,
+        );
+    }
 }
@@ -1,5 +1,10 @@
-use crate::linting::{Lint, LintKind, Linter, Suggestion};
-use crate::{CharString, Document, Punctuation, Span, Token, TokenKind, WordMetadata};
+use serde::{Deserialize, Serialize};
+
+use super::phrase_rules::PhraseRules;
+use crate::linting::{Lint, LintKind, Linter, LinterDescription, Suggestion};
+use crate::{
+    CharString, Document, Punctuation, Span, Token, TokenKind, TokenStringExt, WordMetadata,
+};
 
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 struct PatternToken {
@@ -91,14 +96,52 @@ struct Rule {
     replace_with: Vec<char>,
 }
 
+/// A user-defined replacement rule for the [`Matcher`].
+///
+/// Rules are usually loaded from a JSON file containing an array of them:
+///
+/// ```json
+/// [
+///   { "pattern": "e-mail", "replace_with": "email" },
+///   {
+///     "pattern": "click on",
+///     "replace_with": "click",
+///     "message": "Our style guide prefers “click”.",
+///     "case_sensitive": true
+///   }
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatcherRule {
+    /// The text to look for. It may span several words.
+    pub pattern: String,
+    /// The text to suggest in its place.
+    pub replace_with: String,
+    /// The message to show the user.
+    /// If [`None`], a generic one will be generated from the replacement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Whether the case of the words in the pattern must match exactly.
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct MatcherConfig {
+    /// Rules to check in addition to the built-in ones.
+    pub rules: Vec<MatcherRule>,
+}
+
 /// A linter that uses a variety of curated pattern matches to find and fix
 /// common grammatical issues.
 pub struct Matcher {
     triggers: Vec<Rule>,
+    user_rules: PhraseRules<MatcherRule>,
 }
 
 impl Matcher {
-    pub fn new() -> Self {
+    pub fn new(config: MatcherConfig) -> Self {
         // This match list needs to be automatically expanded instead of explicitly
         // defined like it is now.
         let mut triggers = pt! {
@@ -168,17 +211,7 @@ impl Matcher {
             "miss","used" => "misused",
             "bee","there" => "been there",
             "want","be" => "won't be",
-            "more","then" => "more than",
             "gong","to" => "going to",
-            "then","others" => "than others",
-            "Then","others" => "than others",
-            "then","before" => "than before",
-            "Then","before" => "than before",
-            "then","last","week" => "than last week",
-            "then","her" => "than her",
-            "then","hers" => "than hers",
-            "then","him" => "than him",
-            "then","his" => "than his",
             "simply","grammatical" => "simple grammatical",
             "you","r" => "your",
             "you","re" => "you're",
@@ -308,13 +341,22 @@ impl Matcher {
             replace_with: vecword!("large language model"),
         });
 
-        Self { triggers }
+        let mut user_rules = PhraseRules::default();
+
+        for rule in &config.rules {
+            user_rules.push(&rule.pattern, rule.case_sensitive, rule.clone());
+        }
+
+        Self {
+            triggers,
+            user_rules,
+        }
     }
 }
 
 impl Default for Matcher {
     fn default() -> Self {
-        Self::new()
+        Self::new(MatcherConfig::default())
     }
 }
 
@@ -351,6 +393,7 @@ impl Linter for Matcher {
                     lints.push(Lint {
                         span,
                         lint_kind: LintKind::Miscellaneous,
+                        rule_id: "Matcher".to_string(),
                         suggestions: vec![Suggestion::ReplaceWith(trigger.replace_with.to_owned())],
                         message: format!(
                             "Did you mean “{}”?",
@@ -362,20 +405,120 @@ impl Linter for Matcher {
             }
         }
 
+        let tokens = document.get_tokens();
+
+        for (found, rule) in self.user_rules.find_all(document) {
+            let span = tokens[found].span().unwrap();
+
+            lints.push(Lint {
+                span,
+                lint_kind: LintKind::Miscellaneous,
+                rule_id: "Matcher".to_string(),
+                suggestions: vec![if rule.case_sensitive {
+                    Suggestion::ReplaceWith(rule.replace_with.chars().collect())
+                } else {
+                    Suggestion::replace_with_match_case(
+                        rule.replace_with.chars().collect(),
+                        document.get_span_content(span),
+                    )
+                }],
+                message: rule
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| format!("Did you mean “{}”?", rule.replace_with)),
+                priority: 15,
+            })
+        }
+
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Matcher",
+            "Fixes common mistakes from a curated list of phrases.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Compares the text against a list of common misspellings and awkward phrases, suggesting a replacement for each.",
+        )
+        .with_good_example("I stored them in a hash map.")
+        .with_bad_example("I stored them in a hashmap.")
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Linter, Matcher};
+    use super::{Linter, Matcher, MatcherConfig, MatcherRule};
     use crate::Document;
 
     #[test]
     fn matches_therefore() {
         let document = Document::new_plain_english_curated("There fore.");
-        let mut matcher = Matcher::new();
+        let mut matcher = Matcher::default();
+        let lints = matcher.lint(&document);
+        assert_eq!(lints.len(), 1);
+    }
+
+    #[test]
+    fn matches_user_rule() {
+        let document = Document::new_plain_english_curated("Please click on the button.");
+        let mut matcher = Matcher::new(
+            serde_json::from_str(
+                r#"{ "rules": [{ "pattern": "click on", "replace_with": "click", "message": "Just click." }] }"#,
+            )
+            .unwrap(),
+        );
+        let lints = matcher.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].message, "Just click.");
+    }
+
+    #[test]
+    fn user_rule_ignores_case_by_default() {
+        let document = Document::new_plain_english_curated("Send an E-Mail.");
+        let mut matcher = Matcher::new(
+            serde_json::from_str(
+                r#"{ "rules": [{ "pattern": "e-mail", "replace_with": "email" }] }"#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(matcher.lint(&document).len(), 1);
+    }
+
+    #[test]
+    fn user_rules_never_overlap() {
+        let document = Document::new_plain_english_curated("Please click on the button.");
+        let mut matcher = Matcher::new(
+            serde_json::from_str(
+                r#"{ "rules": [
+                    { "pattern": "click on", "replace_with": "click" },
+                    { "pattern": "click on the button", "replace_with": "click the button" },
+                    { "pattern": "on the", "replace_with": "on a" }
+                ] }"#,
+            )
+            .unwrap(),
+        );
         let lints = matcher.lint(&document);
+
         assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].message, "Did you mean “click the button”?");
+    }
+
+    #[test]
+    fn user_rule_respects_case_sensitivity() {
+        let document = Document::new_plain_english_curated("Send an E-Mail.");
+        let mut matcher = Matcher::new(MatcherConfig {
+            rules: vec![MatcherRule {
+                pattern: "e-mail".to_string(),
+                replace_with: "email".to_string(),
+                message: None,
+                case_sensitive: true,
+            }],
+        });
+
+        assert_eq!(matcher.lint(&document).len(), 0);
     }
 }
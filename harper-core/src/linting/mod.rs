@@ -1,10 +1,16 @@
 mod an_a;
+mod autofix;
 mod avoid_curses;
 mod boring_words;
 mod capitalize_personal_pronouns;
+mod confusables;
 mod correct_number_suffix;
+mod description;
+mod dialect_spelling;
 mod dot_initialisms;
 mod ellipsis_length;
+mod heading_capitalization;
+mod inclusive_language;
 mod linking_verbs;
 mod lint;
 mod lint_group;
@@ -12,42 +18,65 @@ mod long_sentences;
 mod matcher;
 mod multiple_sequential_pronouns;
 mod number_suffix_capitalization;
+mod paragraph_readability;
+mod passive_voice;
 mod pattern_linter;
+mod phrase_rules;
 mod repeated_words;
 mod sentence_capitalization;
 mod spaces;
 mod spell_check;
 mod spelled_numbers;
+mod style_preset;
+mod subject_verb_agreement;
 mod terminating_conjunctions;
+mod terminology;
 mod that_which;
 mod unclosed_quotes;
+mod undefined_acronyms;
 mod use_genitive;
+mod wordiness;
 mod wrong_quotes;
 
 pub use an_a::AnA;
+pub use autofix::{apply_edits, Autofixer, Edit, FixPolicy, FixResult};
 pub use avoid_curses::AvoidCurses;
-pub use boring_words::BoringWords;
+pub use boring_words::{BoringWords, BoringWordsConfig};
 pub use capitalize_personal_pronouns::CapitalizePersonalPronouns;
+pub use confusables::Confusables;
 pub use correct_number_suffix::CorrectNumberSuffix;
+pub use description::LinterDescription;
+pub use dialect_spelling::DialectSpelling;
 pub use dot_initialisms::DotInitialisms;
 pub use ellipsis_length::EllipsisLength;
+pub use heading_capitalization::{
+    HeadingCapitalization, HeadingCapitalizationConfig, HeadingStyle,
+};
+pub use inclusive_language::{InclusiveLanguage, InclusiveLanguageConfig, InclusiveTerm};
 pub use linking_verbs::LinkingVerbs;
-pub use lint::{Lint, LintKind, Suggestion};
-pub use lint_group::{LintGroup, LintGroupConfig};
-pub use long_sentences::LongSentences;
-pub use matcher::Matcher;
+pub use lint::{Lint, LintKind, Replacement, Suggestion};
+pub use lint_group::{LintGroup, LintGroupConfig, LinterMetadata, LinterSetting};
+pub use long_sentences::{LongSentences, LongSentencesConfig};
+pub use matcher::{Matcher, MatcherConfig, MatcherRule};
 pub use multiple_sequential_pronouns::MultipleSequentialPronouns;
 pub use number_suffix_capitalization::NumberSuffixCapitalization;
+pub use paragraph_readability::{ParagraphReadability, ParagraphReadabilityConfig};
+pub use passive_voice::PassiveVoice;
 pub use pattern_linter::PatternLinter;
 pub use repeated_words::RepeatedWords;
 pub use sentence_capitalization::SentenceCapitalization;
 pub use spaces::Spaces;
-pub use spell_check::SpellCheck;
+pub use spell_check::{SpellCheck, SpellCheckConfig};
 pub use spelled_numbers::SpelledNumbers;
+pub use style_preset::{StylePreset, UnknownStylePresetError};
+pub use subject_verb_agreement::SubjectVerbAgreement;
 pub use terminating_conjunctions::TerminatingConjunctions;
+pub use terminology::{GlossaryEntry, Terminology, TerminologyConfig};
 pub use that_which::ThatWhich;
 pub use unclosed_quotes::UnclosedQuotes;
+pub use undefined_acronyms::{UndefinedAcronyms, UndefinedAcronymsConfig};
 pub use use_genitive::UseGenitive;
+pub use wordiness::{Wordiness, WordinessConfig, WordyPhrase};
 pub use wrong_quotes::WrongQuotes;
 
 use crate::Document;
@@ -55,15 +84,39 @@ use crate::Document;
 #[cfg(not(feature = "concurrent"))]
 pub trait Linter {
     fn lint(&mut self, document: &Document) -> Vec<Lint>;
+
+    /// Explain the rule the linter enforces.
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::default()
+    }
+
+    /// Apply the options a [`LintGroupConfig`] sets for the linter.
+    ///
+    /// This is only called for linters added with [`LintGroup::register`].
+    /// `options` is an object holding every key of the linter's setting
+    /// except `enabled`, and is empty if the setting is a plain boolean.
+    fn configure(&mut self, _options: &serde_json::Value) {}
 }
 #[cfg(feature = "concurrent")]
 pub trait Linter: Send + Sync {
     fn lint(&mut self, document: &Document) -> Vec<Lint>;
+
+    /// Explain the rule the linter enforces.
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::default()
+    }
+
+    /// Apply the options a [`LintGroupConfig`] sets for the linter.
+    ///
+    /// This is only called for linters added with [`LintGroup::register`].
+    /// `options` is an object holding every key of the linter's setting
+    /// except `enabled`, and is empty if the setting is a plain boolean.
+    fn configure(&mut self, _options: &serde_json::Value) {}
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Linter;
+    use super::{Autofixer, Linter};
     use crate::Document;
 
     pub fn assert_lint_count(text: &str, mut linter: impl Linter, count: usize) {
@@ -79,15 +132,8 @@ mod tests {
         let test = Document::new_markdown_curated(text);
         let lints = linter.lint(&test);
 
-        let mut text: Vec<char> = text.chars().collect();
-
-        for lint in lints {
-            if let Some(sug) = lint.suggestions.first() {
-                sug.apply(lint.span, &mut text);
-            }
-        }
-
-        let transformed_str: String = text.iter().collect();
+        let text: Vec<char> = text.chars().collect();
+        let transformed_str = Autofixer::default().fix_lints(&text, &lints).text_string();
 
         assert_eq!(transformed_str.as_str(), expected_result);
     }
@@ -1,9 +1,8 @@
 use hashbrown::HashSet;
 
 use super::pattern_linter::PatternLinter;
-use super::Suggestion;
-use crate::linting::LintKind;
-use crate::patterns::{Pattern, SequencePattern};
+use super::{LintKind, LinterDescription, Suggestion};
+use crate::patterns::{Captures, Pattern, SequencePattern};
 use crate::{Lint, Lrc, Token, TokenStringExt};
 
 /// Linter that checks if multiple pronouns are being used right after each
@@ -41,7 +40,12 @@ impl PatternLinter for MultipleSequentialPronouns {
         self.pattern.as_ref()
     }
 
-    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint {
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        source: &[char],
+        _captures: &Captures,
+    ) -> Lint {
         let mut suggestions = Vec::new();
 
         if matched_tokens.len() == 3 {
@@ -56,11 +60,25 @@ impl PatternLinter for MultipleSequentialPronouns {
         Lint {
             span: matched_tokens.span().unwrap(),
             lint_kind: LintKind::Repetition,
+            rule_id: "MultipleSequentialPronouns".to_string(),
             message: "There are too many personal pronouns in sequence here.".to_owned(),
             priority: 63,
             suggestions,
         }
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Multiple Sequential Pronouns",
+            "Looks for several pronouns in a row, a common revision mistake.",
+            LintKind::Repetition,
+        )
+        .with_long_description(
+            "Two personal pronouns next to each other usually means that one was left behind while revising the sentence.",
+        )
+        .with_good_example("Tell me a little bit about what I want to do.")
+        .with_bad_example("Tell me a little bit about my I want to do.")
+    }
 }
 
 impl Default for MultipleSequentialPronouns {
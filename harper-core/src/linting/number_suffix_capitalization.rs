@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::token::TokenStringExt;
 use crate::{Document, Span, TokenKind};
 
@@ -22,6 +22,7 @@ impl Linter for NumberSuffixCapitalization {
                 output.push(Lint {
                     span: suffix_span,
                     lint_kind: LintKind::Capitalization,
+                    rule_id: "NumberSuffixCapitalization".to_string(),
                     message: "This suffix should be lowercase".to_string(),
                     suggestions: vec![Suggestion::ReplaceWith(
                         chars.iter().map(|c| c.to_ascii_lowercase()).collect(),
@@ -33,6 +34,19 @@ impl Linter for NumberSuffixCapitalization {
 
         output
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Number Suffix Capitalization",
+            "Checks that ordinal suffixes are not capitalized.",
+            LintKind::Capitalization,
+        )
+        .with_long_description(
+            "The suffixes of ordinal numbers (like “nd” in “2nd”) should be written in lowercase.",
+        )
+        .with_good_example("She came in 2nd place.")
+        .with_bad_example("She came in 2ND place.")
+    }
 }
 
 #[cfg(test)]
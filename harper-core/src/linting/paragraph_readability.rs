@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, LinterDescription};
+use crate::readability::ReadabilityStats;
+use crate::token::TokenStringExt;
+use crate::{Document, Span};
+
+/// Paragraphs with fewer words than this are too short for the grade level to
+/// mean much.
+const MIN_WORDS: usize = 20;
+
+/// Options for the [`ParagraphReadability`] linter.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParagraphReadabilityConfig {
+    /// The highest Flesch-Kincaid grade level a paragraph may have before it
+    /// is flagged.
+    pub max_grade: f32,
+}
+
+impl Default for ParagraphReadabilityConfig {
+    fn default() -> Self {
+        Self { max_grade: 12. }
+    }
+}
+
+/// Detect paragraphs that are too hard to read, according to their
+/// Flesch-Kincaid grade level.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParagraphReadability {
+    config: ParagraphReadabilityConfig,
+}
+
+impl ParagraphReadability {
+    pub fn new(config: ParagraphReadabilityConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Linter for ParagraphReadability {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut output = Vec::new();
+
+        for paragraph in document.iter_paragraphs() {
+            let stats = ReadabilityStats::from_tokens(paragraph, document.get_source());
+
+            if stats.words < MIN_WORDS {
+                continue;
+            }
+
+            let grade = stats.flesch_kincaid_grade();
+
+            if grade > self.config.max_grade {
+                let (Some(first), Some(last)) = (paragraph.first_word(), paragraph.last_word())
+                else {
+                    continue;
+                };
+
+                output.push(Lint {
+                    span: Span::new(first.span.start, last.span.end),
+                    lint_kind: LintKind::Readability,
+                    rule_id: "ParagraphReadability".to_string(),
+                    message: format!(
+                        "This paragraph reads at grade level {:.1}. Consider shorter sentences and simpler words.",
+                        grade
+                    ),
+                    ..Default::default()
+                })
+            }
+        }
+
+        output
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Paragraph Readability",
+            "Looks for paragraphs that are too difficult to read.",
+            LintKind::Readability,
+        )
+        .with_long_description(
+            "Estimates the school grade level needed to understand each paragraph with the Flesch-Kincaid formula, which favors short sentences and short words.",
+        )
+        .with_good_example("We tested the new build on three phones. It ran well on all of them, and the battery lasted a full day. We plan to ship it next week.")
+        .with_bad_example("Comprehensive evaluation of the experimental configuration across heterogeneous hardware environments demonstrated considerable improvements in operational efficiency, particularly regarding energy consumption characteristics under sustained computational utilization.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParagraphReadability, ParagraphReadabilityConfig};
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn allows_simple_paragraph() {
+        assert_lint_count(
+            "We tested the new build on three phones. It ran well on all of them, and the battery lasted a full day. We plan to ship it next week.",
+            ParagraphReadability::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_only_difficult_paragraph() {
+        assert_lint_count(
+            "We tested the new build on three phones. It ran well on all of them, and the battery lasted a full day.\n\nComprehensive evaluation of the experimental configuration across heterogeneous hardware environments demonstrated considerable improvements in operational efficiency, particularly regarding energy consumption characteristics under sustained computational utilization.",
+            ParagraphReadability::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn ignores_short_paragraphs() {
+        assert_lint_count(
+            "Interdepartmental communication necessitates institutional reorganization.",
+            ParagraphReadability::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn respects_max_grade() {
+        assert_lint_count(
+            "We tested the new build on three phones. It ran well on all of them, and the battery lasted a full day. We plan to ship it next week.",
+            ParagraphReadability::new(ParagraphReadabilityConfig { max_grade: 1. }),
+            1,
+        );
+    }
+}
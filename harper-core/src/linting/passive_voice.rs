@@ -0,0 +1,141 @@
+use super::{Lint, LintKind, LinterDescription, PatternLinter};
+use crate::patterns::{Captures, EitherPattern, Pattern, SequencePattern};
+use crate::{Token, TokenStringExt, WordMetadata};
+
+/// Looks for forms of "to be" followed by a past participle, like "was eaten".
+pub struct PassiveVoice {
+    pattern: Box<dyn Pattern>,
+}
+
+impl Default for PassiveVoice {
+    fn default() -> Self {
+        let participle = |tok: &Token, _source: &[char]| {
+            tok.kind
+                .as_word()
+                .is_some_and(WordMetadata::is_past_participle_verb)
+        };
+
+        // Adverbs may sit between the two, like in "was quickly eaten".
+        let adverbs_then_participle = SequencePattern::default()
+            .then_one_or_more(Box::new(
+                SequencePattern::default().then_adverb().then_whitespace(),
+            ))
+            .then(Box::new(participle));
+
+        // The dictionary marks the forms of "to be" as linking verbs.
+        let pattern = SequencePattern::default()
+            .then_linking_verb()
+            .then_whitespace()
+            .then(Box::new(EitherPattern::new(vec![
+                Box::new(adverbs_then_participle),
+                Box::new(participle),
+            ])));
+
+        Self {
+            pattern: Box::new(pattern),
+        }
+    }
+}
+
+impl PatternLinter for PassiveVoice {
+    fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        source: &[char],
+        _captures: &Captures,
+    ) -> Lint {
+        let span = matched_tokens.span().unwrap();
+
+        Lint {
+            span,
+            lint_kind: LintKind::Readability,
+            rule_id: "PassiveVoice".to_string(),
+            message: format!(
+                "“{}” is in the passive voice. Consider saying who performs the action.",
+                span.get_content_string(source)
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Passive Voice",
+            "Looks for passive constructions, like “was eaten”.",
+            LintKind::Readability,
+        )
+        .with_long_description(
+            "The passive voice hides who is doing what. Sentences in the active voice are usually shorter and easier to follow.",
+        )
+        .with_good_example("The committee approved the proposal.")
+        .with_bad_example("The proposal was approved by the committee.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PassiveVoice;
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn flags_regular_participle() {
+        assert_lint_count(
+            "The proposal was approved by the committee.",
+            PassiveVoice::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_irregular_participle() {
+        assert_lint_count(
+            "The cake was eaten before noon.",
+            PassiveVoice::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_participle_after_adverb() {
+        assert_lint_count(
+            "The bug has been quickly fixed.",
+            PassiveVoice::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn allows_active_voice() {
+        assert_lint_count(
+            "The committee approved the proposal.",
+            PassiveVoice::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_doubled_consonant_participle() {
+        assert_lint_count("The train was stopped.", PassiveVoice::default(), 1);
+    }
+
+    #[test]
+    fn allows_adjectives_ending_in_ed() {
+        assert_lint_count("The car is red.", PassiveVoice::default(), 0);
+    }
+
+    #[test]
+    fn allows_adjectives_ending_in_ly() {
+        assert_lint_count("The bus was early.", PassiveVoice::default(), 0);
+        assert_lint_count("The bus was only delayed.", PassiveVoice::default(), 1);
+        assert_lint_count("They are family.", PassiveVoice::default(), 0);
+    }
+
+    #[test]
+    fn allows_progressive() {
+        assert_lint_count("She is eating the cake.", PassiveVoice::default(), 0);
+    }
+}
@@ -1,19 +1,35 @@
-use super::{Lint, Linter};
-use crate::patterns::Pattern;
+use super::{Lint, Linter, LinterDescription};
+use crate::patterns::{Captures, MatchContext, Pattern};
 use crate::{Token, TokenStringExt};
 
 #[cfg(not(feature = "concurrent"))]
 pub trait PatternLinter {
     /// A simple getter for the pattern to be searched for.
     fn pattern(&self) -> &dyn Pattern;
-    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint;
+    /// Produce a lint for a match of [`Self::pattern`].
+    /// The spans in `captures` are relative to the start of `matched_tokens`.
+    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char], captures: &Captures)
+        -> Lint;
+
+    /// Explain the rule the linter enforces.
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::default()
+    }
 }
 
 #[cfg(feature = "concurrent")]
 pub trait PatternLinter: Send + Sync {
     /// A simple getter for the pattern to be searched for.
     fn pattern(&self) -> &dyn Pattern;
-    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint;
+    /// Produce a lint for a match of [`Self::pattern`].
+    /// The spans in `captures` are relative to the start of `matched_tokens`.
+    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char], captures: &Captures)
+        -> Lint;
+
+    /// Explain the rule the linter enforces.
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::default()
+    }
 }
 
 impl<L> Linter for L
@@ -23,20 +39,39 @@ where
     fn lint(&mut self, document: &crate::Document) -> Vec<Lint> {
         let mut lints = Vec::new();
         let source = document.get_source();
+        let tokens = document.get_tokens();
 
+        let mut captures = Captures::default();
+        let mut chunk_start = 0;
+
+        // Matches may not cross chunk boundaries, but patterns can still look
+        // at the rest of the document.
         for chunk in document.iter_chunks() {
-            let mut tok_cursor = 0;
+            let chunk_end = chunk_start + chunk.len();
+            let mut tok_cursor = chunk_start;
 
             loop {
-                if tok_cursor >= chunk.len() {
+                if tok_cursor >= chunk_end {
                     break;
                 }
 
-                let match_len = self.pattern().matches(&chunk[tok_cursor..], source);
+                let context = MatchContext {
+                    tokens,
+                    index: tok_cursor,
+                    end: chunk_end,
+                };
+
+                captures.clear();
+                let match_len = self
+                    .pattern()
+                    .matches_in_context(context, source, &mut captures);
 
                 if match_len != 0 {
-                    let lint =
-                        self.match_to_lint(&chunk[tok_cursor..tok_cursor + match_len], source);
+                    let lint = self.match_to_lint(
+                        &tokens[tok_cursor..tok_cursor + match_len],
+                        source,
+                        &captures,
+                    );
 
                     lints.push(lint);
                     tok_cursor += match_len;
@@ -44,8 +79,78 @@ where
                     tok_cursor += 1;
                 }
             }
+
+            chunk_start = chunk_end;
         }
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        PatternLinter::describe(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatternLinter;
+    use crate::linting::tests::assert_lint_count;
+    use crate::linting::Lint;
+    use crate::patterns::{Captures, Lookbehind, Pattern, SequencePattern, StartOf};
+    use crate::{Token, TokenStringExt};
+
+    /// Flags lowercase words at the start of a sentence.
+    struct LowercaseStart {
+        pattern: Box<dyn Pattern>,
+    }
+
+    impl LowercaseStart {
+        fn new(pattern: Box<dyn Pattern>) -> Self {
+            Self { pattern }
+        }
+    }
+
+    impl PatternLinter for LowercaseStart {
+        fn pattern(&self) -> &dyn Pattern {
+            self.pattern.as_ref()
+        }
+
+        fn match_to_lint(&self, matched_tokens: &[Token], _source: &[char], _: &Captures) -> Lint {
+            Lint {
+                span: matched_tokens.span().unwrap(),
+                ..Default::default()
+            }
+        }
+    }
+
+    fn lowercase_word() -> Box<dyn Pattern> {
+        Box::new(|tok: &Token, source: &[char]| {
+            tok.kind.is_word() && tok.span.get_content(source)[0].is_lowercase()
+        })
+    }
+
+    #[test]
+    fn anchors_see_previous_chunks() {
+        let linter = LowercaseStart::new(Box::new(StartOf::sentence(lowercase_word())));
+
+        assert_lint_count("This is fine, but this is not. this is bad.", linter, 1);
+    }
+
+    #[test]
+    fn lookbehind_sees_previous_chunks() {
+        let linter = LowercaseStart::new(Box::new(Lookbehind::new(
+            Box::new(
+                SequencePattern::default()
+                    .then_exact_word("fine")
+                    .then_comma(),
+            ),
+            Box::new(
+                SequencePattern::default()
+                    .then_whitespace()
+                    .then(lowercase_word()),
+            ),
+        )));
+
+        assert_lint_count("This is fine, but that is not.", linter, 1);
+    }
 }
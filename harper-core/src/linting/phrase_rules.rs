@@ -0,0 +1,107 @@
+use std::ops::Range;
+
+use crate::patterns::{Pattern, SequencePattern};
+use crate::Document;
+
+/// A list of phrases, each with some data attached, that are searched for
+/// together so that no two matches overlap.
+///
+/// Where several phrases match at the same place, the longest one wins, so
+/// “master/slave” is found instead of the “slave” inside it.
+pub(crate) struct PhraseRules<T> {
+    rules: Vec<(SequencePattern, T)>,
+}
+
+impl<T> PhraseRules<T> {
+    /// Add a phrase to look for.
+    /// Unless `case_sensitive` is set, it is matched regardless of case.
+    pub fn push(&mut self, phrase: &str, case_sensitive: bool, data: T) {
+        let pattern = if case_sensitive {
+            SequencePattern::from_exact_phrase(phrase)
+        } else {
+            SequencePattern::from_phrase(phrase)
+        };
+
+        self.rules.push((pattern, data));
+    }
+
+    /// Find every phrase in the document, returning the range of tokens each
+    /// match covers along with the data of the phrase that matched.
+    pub fn find_all<'a>(&'a self, document: &Document) -> Vec<(Range<usize>, &'a T)> {
+        let tokens = document.get_tokens();
+        let source = document.get_source();
+
+        let mut found = Vec::new();
+        let mut cursor = 0;
+
+        while cursor < tokens.len() {
+            let longest = self
+                .rules
+                .iter()
+                .map(|(pattern, data)| (pattern.matches(&tokens[cursor..], source), data))
+                .filter(|(len, _)| *len > 0)
+                .fold(None, |best: Option<(usize, &T)>, (len, data)| match best {
+                    Some((best_len, _)) if best_len >= len => best,
+                    _ => Some((len, data)),
+                });
+
+            if let Some((len, data)) = longest {
+                found.push((cursor..cursor + len, data));
+                cursor += len;
+            } else {
+                cursor += 1;
+            }
+        }
+
+        found
+    }
+}
+
+impl<T> Default for PhraseRules<T> {
+    fn default() -> Self {
+        Self { rules: Vec::new() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PhraseRules;
+    use crate::Document;
+
+    fn found<'a>(rules: &PhraseRules<&'a str>, text: &str) -> Vec<&'a str> {
+        let document = Document::new_plain_english_curated(text);
+
+        rules
+            .find_all(&document)
+            .into_iter()
+            .map(|(_, data)| *data)
+            .collect()
+    }
+
+    #[test]
+    fn prefers_longest_phrase() {
+        let mut rules = PhraseRules::default();
+        rules.push("slave", false, "short");
+        rules.push("master/slave", false, "long");
+
+        assert_eq!(found(&rules, "Set up master/slave replication."), ["long"]);
+    }
+
+    #[test]
+    fn never_overlaps() {
+        let mut rules = PhraseRules::default();
+        rules.push("click on", false, "first");
+        rules.push("on the button", false, "second");
+
+        assert_eq!(found(&rules, "Click on the button."), ["first"]);
+    }
+
+    #[test]
+    fn respects_case_sensitivity() {
+        let mut rules = PhraseRules::default();
+        rules.push("e-mail", true, "exact");
+
+        assert!(found(&rules, "Send an E-Mail.").is_empty());
+        assert_eq!(found(&rules, "Send an e-mail."), ["exact"]);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::token::TokenStringExt;
 use crate::{CharStringExt, Document, Span};
 
@@ -26,6 +26,7 @@ impl Linter for RepeatedWords {
                     lints.push(Lint {
                         span: Span::new(tok_a.span.start, tok_b.span.end),
                         lint_kind: LintKind::Repetition,
+                        rule_id: "RepeatedWords".to_string(),
                         suggestions: vec![Suggestion::ReplaceWith(
                             document.get_span_content(tok_a.span).to_vec(),
                         )],
@@ -38,6 +39,19 @@ impl Linter for RepeatedWords {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Repeated Words",
+            "Looks for words that have accidentally been repeated.",
+            LintKind::Repetition,
+        )
+        .with_long_description(
+            "When editing, it is easy to leave behind a second copy of a word. Some words, like “that that”, can be repeated on purpose, so those are left alone.",
+        )
+        .with_good_example("I wanted the banana.")
+        .with_bad_example("I wanted the the banana.")
+    }
 }
 
 #[cfg(test)]
@@ -50,6 +64,11 @@ mod tests {
         assert_lint_count("I wanted the the banana.", RepeatedWords, 1)
     }
 
+    #[test]
+    fn catches_this_this() {
+        assert_lint_count("Here is a sentence like this this one.", RepeatedWords, 1)
+    }
+
     #[test]
     fn does_not_lint_homographs_address() {
         assert_lint_count("To address address problems.", RepeatedWords, 0);
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 
 use super::lint::Suggestion;
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Linter, LinterDescription};
 use crate::document::Document;
 use crate::{Token, TokenKind, TokenStringExt};
 
@@ -45,6 +45,7 @@ impl Linter for SentenceCapitalization {
                             lints.push(Lint {
                                 span: first_word.span.with_len(1),
                                 lint_kind: LintKind::Capitalization,
+                                rule_id: "SentenceCapitalization".to_string(),
                                 suggestions: vec![Suggestion::ReplaceWith(
                                     first_letter.to_uppercase().collect_vec(),
                                 )],
@@ -60,6 +61,19 @@ impl Linter for SentenceCapitalization {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Sentence Capitalization",
+            "Checks that sentences start with a capital letter.",
+            LintKind::Capitalization,
+        )
+        .with_long_description(
+            "The first word of every sentence should be capitalized, so readers can easily tell where one sentence ends and the next begins.",
+        )
+        .with_good_example("There is no way she is guilty.")
+        .with_bad_example("there is no way she is guilty.")
+    }
 }
 
 fn is_full_sentence(toks: &[Token]) -> bool {
@@ -162,4 +176,13 @@ mod tests {
     fn issue_228_allows_labels() {
         assert_lint_count("python lsp (fork of pyright)", SentenceCapitalization, 0)
     }
+
+    #[test]
+    fn unphased_abbreviations() {
+        assert_lint_count(
+            "The meeting starts at approx. nine and ends at noon, i.e. three hours later.",
+            SentenceCapitalization,
+            0,
+        )
+    }
 }
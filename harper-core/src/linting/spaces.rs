@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::token::TokenStringExt;
 use crate::{Document, Token, TokenKind};
 
@@ -19,6 +19,7 @@ impl Linter for Spaces {
                     output.push(Lint {
                         span: space.span,
                         lint_kind: LintKind::Formatting,
+                        rule_id: "Spaces".to_string(),
                         suggestions: vec![Suggestion::ReplaceWith(vec![' '])],
                         message: format!(
                             "There are {} spaces where there should be only one.",
@@ -52,6 +53,7 @@ impl Linter for Spaces {
                         .span()
                         .unwrap(),
                     lint_kind: LintKind::Formatting,
+                    rule_id: "Spaces".to_string(),
                     suggestions: vec![Suggestion::Remove],
                     message: "Unnecessary space at the end of the sentence.".to_string(),
                     priority: 63,
@@ -61,6 +63,19 @@ impl Linter for Spaces {
 
         output
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Spaces",
+            "Looks for unnecessary spaces between words.",
+            LintKind::Formatting,
+        )
+        .with_long_description(
+            "Words should be separated by a single space, and there should be no space before the punctuation at the end of a sentence.",
+        )
+        .with_good_example("There is one space between these words.")
+        .with_bad_example("There are two  spaces here.")
+    }
 }
 
 #[cfg(test)]
@@ -1,18 +1,34 @@
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
 use smallvec::ToSmallVec;
 
 use super::lint::Suggestion;
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Linter, LinterDescription};
 use crate::document::Document;
 use crate::spell::suggest_correct_spelling;
 use crate::{CharString, Dictionary, TokenStringExt};
 
+/// Options for the [`SpellCheck`] linter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellCheckConfig {
+    /// The maximum number of corrections to suggest for each misspelled word.
+    pub max_suggestions: usize,
+}
+
+impl Default for SpellCheckConfig {
+    fn default() -> Self {
+        Self { max_suggestions: 5 }
+    }
+}
+
 pub struct SpellCheck<T>
 where
     T: Dictionary,
 {
     dictionary: T,
     word_cache: HashMap<CharString, Vec<CharString>>,
+    config: SpellCheckConfig,
 }
 
 impl<T: Dictionary> SpellCheck<T> {
@@ -20,8 +36,13 @@ impl<T: Dictionary> SpellCheck<T> {
         Self {
             dictionary,
             word_cache: HashMap::new(),
+            config: SpellCheckConfig::default(),
         }
     }
+
+    pub fn set_config(&mut self, config: SpellCheckConfig) {
+        self.config = config;
+    }
 }
 
 impl<T: Dictionary> SpellCheck<T> {
@@ -62,26 +83,17 @@ impl<T: Dictionary> Linter for SpellCheck<T> {
 
             let mut possibilities = self.cached_suggest_correct_spelling(word_chars);
 
-            if possibilities.len() > 5 {
-                possibilities.resize_with(5, || panic!());
-            }
-
-            // If the misspelled word is capitalized, capitalize the results too.
-            if let Some(mis_f) = word_chars.first() {
-                if mis_f.is_uppercase() {
-                    for sug_f in possibilities.iter_mut().filter_map(|w| w.first_mut()) {
-                        *sug_f = sug_f.to_uppercase().next().unwrap();
-                    }
-                }
-            }
+            possibilities.truncate(self.config.max_suggestions);
 
+            // Mirror the casing of the misspelled word in the results.
             let suggestions = possibilities
                 .into_iter()
-                .map(|word| Suggestion::ReplaceWith(word.to_vec()));
+                .map(|word| Suggestion::replace_with_match_case(word.to_vec(), word_chars));
 
             lints.push(Lint {
                 span: word.span,
                 lint_kind: LintKind::Spelling,
+                rule_id: "SpellCheck".to_string(),
                 suggestions: suggestions.collect(),
                 message: format!(
                     "\"{}\": Unknown word",
@@ -93,4 +105,46 @@ impl<T: Dictionary> Linter for SpellCheck<T> {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Spell Check",
+            "Looks for words that are not in the dictionary.",
+            LintKind::Spelling,
+        )
+        .with_long_description(
+            "Flags any word that does not appear in the dictionary and suggests similar words that do. Words can be added to the user or file dictionaries to silence false positives.",
+        )
+        .with_good_example("This is a test.")
+        .with_bad_example("This is a tset.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SpellCheck, SpellCheckConfig};
+    use crate::linting::Linter;
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn respects_max_suggestions() {
+        let doc = Document::new_plain_english_curated("thsi");
+
+        let mut linter = SpellCheck::new(FstDictionary::curated());
+        linter.set_config(SpellCheckConfig { max_suggestions: 1 });
+
+        let lints = linter.lint(&doc);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].suggestions.len(), 1);
+    }
+
+    #[test]
+    fn keeps_initialisms_that_end_sentences() {
+        let doc = Document::new_plain_english_curated("Meet me at 5 p.m. Then we go.");
+
+        let mut linter = SpellCheck::new(FstDictionary::curated());
+
+        assert!(linter.lint(&doc).is_empty());
+    }
 }
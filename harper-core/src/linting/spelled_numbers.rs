@@ -1,4 +1,4 @@
-use crate::linting::{LintKind, Linter, Suggestion};
+use crate::linting::{LintKind, Linter, LinterDescription, Suggestion};
 use crate::{Document, Lint, TokenStringExt};
 
 /// Linter that checks to make sure small integers (< 10) are spelled
@@ -18,6 +18,7 @@ impl Linter for SpelledNumbers {
                 lints.push(Lint {
                     span: number_tok.span,
                     lint_kind: LintKind::Readability,
+                    rule_id: "SpelledNumbers".to_string(),
                     suggestions: vec![Suggestion::ReplaceWith(
                         spell_out_number(number as u64).unwrap().chars().collect(),
                     )],
@@ -29,6 +30,19 @@ impl Linter for SpelledNumbers {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Spelled Numbers",
+            "Suggests spelling out numbers less than ten.",
+            LintKind::Readability,
+        )
+        .with_long_description(
+            "Most style guides recommend spelling out small whole numbers in prose, where numerals can look out of place.",
+        )
+        .with_good_example("I have three apples.")
+        .with_bad_example("I have 3 apples.")
+    }
 }
 
 /// Converts a number to it's spelled-out variant.
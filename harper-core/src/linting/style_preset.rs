@@ -0,0 +1,226 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    HeadingCapitalizationConfig, HeadingStyle, LintGroupConfig, LinterSetting, LongSentencesConfig,
+    ParagraphReadabilityConfig,
+};
+use crate::Dialect;
+
+/// A bundle of linter settings that follows a published style guide.
+///
+/// A preset is selected with the `preset` key of a [`LintGroupConfig`].
+/// Any other settings in the config take precedence over the preset's.
+///
+/// ```json
+/// { "preset": "google-dev-docs", "passive_voice": false }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StylePreset {
+    /// The Google developer documentation style guide.
+    GoogleDevDocs,
+    /// The Microsoft Writing Style Guide.
+    Microsoft,
+    /// The U.S. Federal Plain Language Guidelines.
+    PlainLanguage,
+}
+
+impl StylePreset {
+    pub const ALL: [Self; 3] = [Self::GoogleDevDocs, Self::Microsoft, Self::PlainLanguage];
+
+    /// The name the preset is selected by, like `google-dev-docs`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GoogleDevDocs => "google-dev-docs",
+            Self::Microsoft => "microsoft",
+            Self::PlainLanguage => "plain-language",
+        }
+    }
+
+    /// The settings the preset applies.
+    /// Linters it doesn't mention are left at their defaults.
+    pub fn config(&self) -> LintGroupConfig {
+        match self {
+            Self::GoogleDevDocs => LintGroupConfig {
+                dialect: Some(Dialect::American),
+                sentence_capitalization: Some(true),
+                spelled_numbers: Some(true),
+                passive_voice: Some(true),
+                long_sentences: enable(LongSentencesConfig { max_words: 26 }),
+                heading_capitalization: enable(HeadingCapitalizationConfig {
+                    style: HeadingStyle::SentenceCase,
+                }),
+                wordiness: enable(Default::default()),
+                inclusive_language: enable(Default::default()),
+                undefined_acronyms: enable(Default::default()),
+                ..Default::default()
+            },
+            Self::Microsoft => LintGroupConfig {
+                dialect: Some(Dialect::American),
+                sentence_capitalization: Some(true),
+                spelled_numbers: Some(true),
+                passive_voice: Some(true),
+                boring_words: enable(Default::default()),
+                long_sentences: enable(LongSentencesConfig { max_words: 25 }),
+                heading_capitalization: enable(HeadingCapitalizationConfig {
+                    style: HeadingStyle::SentenceCase,
+                }),
+                wordiness: enable(Default::default()),
+                inclusive_language: enable(Default::default()),
+                undefined_acronyms: enable(Default::default()),
+                ..Default::default()
+            },
+            Self::PlainLanguage => LintGroupConfig {
+                sentence_capitalization: Some(true),
+                passive_voice: Some(true),
+                boring_words: enable(Default::default()),
+                long_sentences: enable(LongSentencesConfig { max_words: 20 }),
+                paragraph_readability: enable(ParagraphReadabilityConfig { max_grade: 8. }),
+                wordiness: enable(Default::default()),
+                undefined_acronyms: enable(Default::default()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A setting that enables a linter with the given options.
+fn enable<C>(options: C) -> Option<LinterSetting<C>> {
+    Some(LinterSetting::Configured {
+        enabled: Some(true),
+        options,
+    })
+}
+
+impl Display for StylePreset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "Unknown style preset `{0}`. Expected one of google-dev-docs, microsoft or plain-language."
+)]
+pub struct UnknownStylePresetError(String);
+
+impl FromStr for StylePreset {
+    type Err = UnknownStylePresetError;
+
+    /// Parse a preset from its name, ignoring case.
+    /// Underscores may be used in place of hyphens.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace('_', "-");
+
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name() == name)
+            .ok_or_else(|| UnknownStylePresetError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StylePreset;
+    use crate::linting::{LintGroup, LintGroupConfig, Linter, LinterSetting, LongSentencesConfig};
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn parses_names() {
+        for preset in StylePreset::ALL {
+            assert_eq!(preset.name().parse(), Ok(preset));
+        }
+
+        assert_eq!("Google_Dev_Docs".parse(), Ok(StylePreset::GoogleDevDocs));
+        assert!("chicago".parse::<StylePreset>().is_err());
+    }
+
+    #[test]
+    fn deserializes_from_config() {
+        let config: LintGroupConfig =
+            serde_json::from_str(r#"{ "preset": "plain-language" }"#).unwrap();
+
+        assert_eq!(config.preset, Some(StylePreset::PlainLanguage));
+    }
+
+    #[test]
+    fn enables_linters() {
+        let mut config = LintGroupConfig {
+            preset: Some(StylePreset::GoogleDevDocs),
+            ..Default::default()
+        };
+        config.fill_default_values();
+
+        assert_eq!(config.is_enabled("PassiveVoice"), Some(true));
+        assert_eq!(config.is_enabled("UndefinedAcronyms"), Some(true));
+        assert_eq!(config.long_sentences.unwrap().options().max_words, 26);
+    }
+
+    #[test]
+    fn user_settings_take_precedence() {
+        let mut config: LintGroupConfig = serde_json::from_str(
+            r#"{ "preset": "google-dev-docs", "passive_voice": false, "long_sentences": { "max_words": 10 } }"#,
+        )
+        .unwrap();
+        config.fill_default_values();
+
+        assert_eq!(config.is_enabled("PassiveVoice"), Some(false));
+        assert_eq!(
+            config.long_sentences,
+            Some(LinterSetting::Configured {
+                enabled: Some(true),
+                options: LongSentencesConfig { max_words: 10 }
+            })
+        );
+    }
+
+    #[test]
+    fn settings_keep_preset_options_they_leave_out() {
+        let mut config: LintGroupConfig = serde_json::from_str(
+            r#"{ "preset": "google-dev-docs", "long_sentences": { "enabled": true } }"#,
+        )
+        .unwrap();
+        config.fill_default_values();
+
+        assert_eq!(
+            config.long_sentences,
+            Some(LinterSetting::Configured {
+                enabled: Some(true),
+                options: LongSentencesConfig { max_words: 26 }
+            })
+        );
+    }
+
+    #[test]
+    fn toggles_keep_preset_options() {
+        let mut config: LintGroupConfig =
+            serde_json::from_str(r#"{ "preset": "plain-language", "long_sentences": false }"#)
+                .unwrap();
+        config.fill_default_values();
+
+        assert_eq!(
+            config.long_sentences,
+            Some(LinterSetting::Configured {
+                enabled: Some(false),
+                options: LongSentencesConfig { max_words: 20 }
+            })
+        );
+    }
+
+    #[test]
+    fn applies_to_lint_group() {
+        let doc = Document::new_markdown_curated("The report was written by the team.");
+
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        assert!(linter.lint(&doc).is_empty());
+
+        linter.config.preset = Some(StylePreset::Microsoft);
+        assert!(linter
+            .lint(&doc)
+            .iter()
+            .any(|l| l.rule_id == "PassiveVoice"));
+    }
+}
@@ -0,0 +1,441 @@
+use std::sync::Arc;
+
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
+use crate::token::TokenStringExt;
+use crate::{CharString, CharStringExt, Dictionary, Document, FstDictionary, Token};
+
+/// Pronouns that always take the third-person singular form of a verb.
+const SINGULAR_PRONOUNS: [&str; 2] = ["he", "she"];
+
+/// Pronouns that never take the third-person singular form of a verb.
+const NON_SINGULAR_PRONOUNS: [&str; 4] = ["i", "we", "you", "they"];
+
+/// Forms of "to be" that don't agree with "I", which takes "am" instead.
+const NOT_FIRST_PERSON: [&str; 2] = ["is", "are"];
+
+/// Verbs that don't change with their subject.
+const MODALS: [&str; 10] = [
+    "can", "could", "may", "might", "must", "shall", "should", "will", "would", "dare",
+];
+
+/// Verbs that come before the subject in questions, like "does" in "does
+/// she like it?". The verb after the subject is left in its base form.
+const AUXILIARIES: [&str; 13] = [
+    "do", "does", "did", "don't", "doesn't", "didn't", "is", "are", "was", "were", "has", "have",
+    "had",
+];
+
+/// Nouns that end in "s" but take a singular verb, like "the news is good".
+const SINGULAR_NOUNS_ENDING_IN_S: [&str; 14] = [
+    "news",
+    "physics",
+    "mathematics",
+    "economics",
+    "politics",
+    "ethics",
+    "linguistics",
+    "athletics",
+    "gymnastics",
+    "measles",
+    "series",
+    "species",
+    "means",
+    "whereabouts",
+];
+
+/// Verbs whose past tense is spelled like their base form, so "she put"
+/// agrees with its subject.
+const UNCHANGED_PAST_FORMS: [&str; 14] = [
+    "put", "set", "cut", "hit", "let", "shut", "hurt", "cost", "quit", "spread", "read", "bet",
+    "burst", "cast",
+];
+
+/// Irregular pairs of verb forms, as (third-person singular, other).
+const IRREGULAR_FORMS: [(&str, &str); 7] = [
+    ("is", "are"),
+    ("has", "have"),
+    ("does", "do"),
+    ("isn't", "aren't"),
+    ("hasn't", "haven't"),
+    ("doesn't", "don't"),
+    ("goes", "go"),
+];
+
+/// Looks for subjects directly followed by a verb that doesn't agree with
+/// them in number, like "the results shows" or "he don't".
+pub struct SubjectVerbAgreement {
+    dictionary: Arc<FstDictionary>,
+}
+
+impl Default for SubjectVerbAgreement {
+    fn default() -> Self {
+        Self {
+            dictionary: FstDictionary::curated(),
+        }
+    }
+}
+
+impl SubjectVerbAgreement {
+    /// Find the forms of a verb that agree with a subject of the opposite
+    /// number.
+    fn other_forms(&self, verb: &[char], to_singular: bool) -> Vec<CharString> {
+        let lower: String = verb.to_lower().iter().collect();
+
+        if let Some((singular, other)) = IRREGULAR_FORMS
+            .iter()
+            .find(|(singular, other)| lower == if to_singular { *other } else { *singular })
+        {
+            let form = if to_singular { singular } else { other };
+            return vec![form.chars().collect()];
+        }
+
+        let lower: Vec<char> = lower.chars().collect();
+
+        let candidates: Vec<CharString> = if to_singular {
+            let mut candidates = vec![[lower.as_slice(), &['s']].concat().into()];
+            candidates.push([lower.as_slice(), &['e', 's']].concat().into());
+
+            if let Some(stem) = lower.strip_suffix(&['y']) {
+                candidates.push([stem, &['i', 'e', 's']].concat().into());
+            }
+
+            candidates
+        } else {
+            let mut candidates = Vec::new();
+
+            if let Some(stem) = lower.strip_suffix(&['i', 'e', 's']) {
+                candidates.push([stem, &['y']].concat().into());
+            }
+            if let Some(stem) = lower.strip_suffix(&['e', 's']) {
+                candidates.push(stem.into());
+            }
+            if let Some(stem) = lower.strip_suffix(&['s']) {
+                candidates.push(stem.into());
+            }
+
+            candidates
+        };
+
+        candidates
+            .into_iter()
+            .filter(|candidate| {
+                let metadata = self.dictionary.get_word_metadata(candidate);
+
+                if to_singular {
+                    metadata.is_third_person_singular_verb()
+                } else {
+                    metadata.is_not_third_person_singular_verb()
+                }
+            })
+            .take(1)
+            .collect()
+    }
+}
+
+impl Linter for SubjectVerbAgreement {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut output = Vec::new();
+
+        for chunk in document.iter_chunks() {
+            let words: Vec<(usize, Token)> = chunk
+                .iter()
+                .enumerate()
+                .filter(|(_, tok)| tok.kind.is_word())
+                .map(|(idx, tok)| (idx, *tok))
+                .collect();
+
+            for (pair_idx, pair) in words.windows(2).enumerate() {
+                let [(subject_idx, subject), (verb_idx, verb)] = pair else {
+                    continue;
+                };
+
+                // Only look at words separated by whitespace.
+                if !chunk[subject_idx + 1..*verb_idx]
+                    .iter()
+                    .all(|t| t.kind.is_whitespace())
+                {
+                    continue;
+                }
+
+                let (Some(subject_meta), Some(verb_meta)) =
+                    (subject.kind.as_word(), verb.kind.as_word())
+                else {
+                    continue;
+                };
+
+                // Only look at the readings the words have in this sentence,
+                // so "figures" in "the sales figures are up" isn't read as a
+                // verb.
+                let subject_meta = subject_meta.narrowed();
+                let verb_meta = verb_meta.narrowed();
+
+                if !subject_meta.is_noun() || !verb_meta.is_verb() {
+                    continue;
+                }
+
+                let subject_text: String = document
+                    .get_span_content(subject.span)
+                    .to_lower()
+                    .iter()
+                    .collect();
+                let verb_text = document.get_span_content(verb.span);
+                let verb_lower: String = verb_text.to_lower().iter().collect();
+
+                if MODALS.contains(&verb_lower.as_str())
+                    || UNCHANGED_PAST_FORMS.contains(&verb_lower.as_str())
+                {
+                    continue;
+                }
+
+                // The closest word before the subject, skipping articles and
+                // adjectives.
+                let preceding = words[..pair_idx]
+                    .iter()
+                    .rev()
+                    .find(|(_, tok)| !is_noun_modifier(tok))
+                    .map(|(_, tok)| {
+                        let word: String = document
+                            .get_span_content(tok.span)
+                            .to_lower()
+                            .iter()
+                            .collect();
+
+                        (tok, word)
+                    });
+
+                // In questions, like "does she like it?", and after modals,
+                // like "should she decide?", the verb stays in its base form.
+                let after_auxiliary = preceding.as_ref().is_some_and(|(_, word)| {
+                    MODALS.contains(&word.as_str()) || AUXILIARIES.contains(&word.as_str())
+                });
+
+                // A plural noun after a preposition is rarely the subject, like
+                // in "one of the results shows".
+                let after_preposition = preceding
+                    .as_ref()
+                    .is_some_and(|(tok, _)| tok.kind.as_word().unwrap().preposition);
+
+                // Neither is a plural noun after a gerund, like in "running
+                // tests helps". The tagger often reads the gerund as an
+                // adjective, so the start of the noun phrase is checked too.
+                let phrase_start = words[..pair_idx]
+                    .iter()
+                    .rev()
+                    .take_while(|(_, tok)| is_noun_modifier(tok))
+                    .last();
+                let after_gerund = phrase_start
+                    .map(|(_, tok)| tok)
+                    .into_iter()
+                    .chain(preceding.as_ref().map(|(tok, _)| *tok))
+                    .any(|tok| is_gerund(tok, document));
+
+                if after_auxiliary {
+                    continue;
+                }
+
+                if subject_text == "i" && NOT_FIRST_PERSON.contains(&verb_lower.as_str()) {
+                    output.push(Lint {
+                        span: verb.span,
+                        lint_kind: LintKind::Miscellaneous,
+                        rule_id: "SubjectVerbAgreement".to_string(),
+                        suggestions: vec![Suggestion::replace_with_match_case(
+                            "am".chars().collect(),
+                            verb_text,
+                        )],
+                        message: format!(
+                            "“{}” does not agree with its subject, “I”.",
+                            document.get_span_content_str(verb.span)
+                        ),
+                        priority: 31,
+                    });
+                    continue;
+                }
+
+                let is_plural_subject = NON_SINGULAR_PRONOUNS.contains(&subject_text.as_str())
+                    || (subject_meta.is_plural_noun()
+                        && !subject_meta.is_proper_noun()
+                        && !SINGULAR_NOUNS_ENDING_IN_S.contains(&subject_text.as_str())
+                        && !after_preposition
+                        && !after_gerund);
+
+                let is_singular_subject = SINGULAR_PRONOUNS.contains(&subject_text.as_str());
+
+                let to_singular = if is_plural_subject && verb_meta.is_third_person_singular_verb()
+                {
+                    false
+                } else if is_singular_subject && verb_meta.is_not_third_person_singular_verb() {
+                    true
+                } else {
+                    continue;
+                };
+
+                output.push(Lint {
+                    span: verb.span,
+                    lint_kind: LintKind::Miscellaneous,
+                    rule_id: "SubjectVerbAgreement".to_string(),
+                    suggestions: self
+                        .other_forms(verb_text, to_singular)
+                        .into_iter()
+                        .map(|form| Suggestion::replace_with_match_case(form.to_vec(), verb_text))
+                        .collect(),
+                    message: format!(
+                        "“{}” does not agree with its {} subject, “{}”.",
+                        document.get_span_content_str(verb.span),
+                        if to_singular { "singular" } else { "plural" },
+                        document.get_span_content_str(subject.span)
+                    ),
+                    priority: 31,
+                })
+            }
+        }
+
+        output
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Subject-Verb Agreement",
+            "Checks that verbs agree in number with their subjects.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "A plural subject takes a plural verb, and a singular subject takes a singular one. Only verbs that directly follow their subject are checked.",
+        )
+        .with_good_example("The results show that he doesn't care.")
+        .with_bad_example("The results shows that he don't care.")
+    }
+}
+
+/// Whether a word could sit between a preposition and the noun it
+/// introduces, like "the" or "new" in "one of the new results".
+fn is_noun_modifier(token: &Token) -> bool {
+    let metadata = token.kind.as_word().unwrap();
+
+    // Words like "inside" can be adjectives too, so the part of speech the
+    // word plays here is what matters.
+    metadata.article || metadata.narrowed().is_adjective()
+}
+
+/// Whether a word could be a gerund, like "running" in "running tests".
+fn is_gerund(token: &Token, document: &Document) -> bool {
+    token.kind.is_verb()
+        && document
+            .get_span_content(token.span)
+            .to_lower()
+            .ends_with(&['i', 'n', 'g'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubjectVerbAgreement;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn flags_plural_noun_with_singular_verb() {
+        assert_suggestion_result(
+            "The results shows that it works.",
+            SubjectVerbAgreement::default(),
+            "The results show that it works.",
+        );
+    }
+
+    #[test]
+    fn flags_singular_pronoun_with_plural_verb() {
+        assert_suggestion_result(
+            "He don't like it.",
+            SubjectVerbAgreement::default(),
+            "He doesn't like it.",
+        );
+    }
+
+    #[test]
+    fn flags_plural_pronoun_with_singular_verb() {
+        assert_suggestion_result(
+            "They has a plan.",
+            SubjectVerbAgreement::default(),
+            "They have a plan.",
+        );
+    }
+
+    #[test]
+    fn suggests_regular_forms() {
+        assert_suggestion_result(
+            "She walk to work.",
+            SubjectVerbAgreement::default(),
+            "She walks to work.",
+        );
+    }
+
+    #[test]
+    fn allows_agreeing_verbs() {
+        assert_lint_count(
+            "The results show that he doesn't care, and they have a plan.",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_modals_and_past_tense() {
+        assert_lint_count(
+            "He can swim. She put the book down.",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_compound_nouns() {
+        assert_lint_count(
+            "The sales figures are up.",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_plural_nouns_after_prepositions() {
+        assert_lint_count(
+            "The stuff inside the square brackets is visible.",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_plural_nouns_after_of() {
+        assert_lint_count(
+            "One of the results shows a problem.",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn suggests_am_for_i() {
+        assert_suggestion_result("I is here.", SubjectVerbAgreement::default(), "I am here.");
+    }
+
+    #[test]
+    fn allows_questions_and_modals_before_subject() {
+        assert_lint_count(
+            "Does she like it? Should she decide? Why did he go?",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_singular_nouns_ending_in_s() {
+        assert_lint_count("The news is good.", SubjectVerbAgreement::default(), 0);
+    }
+
+    #[test]
+    fn allows_gerund_subjects() {
+        assert_lint_count(
+            "Running tests helps catch bugs. Writing the docs takes time.",
+            SubjectVerbAgreement::default(),
+            0,
+        );
+    }
+}
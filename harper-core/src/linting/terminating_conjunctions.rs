@@ -1,5 +1,5 @@
-use super::{Lint, LintKind, PatternLinter};
-use crate::patterns::{ConsumesRemainingPattern, Pattern, SequencePattern};
+use super::{Lint, LintKind, LinterDescription, PatternLinter};
+use crate::patterns::{Captures, ConsumesRemainingPattern, Pattern, SequencePattern};
 use crate::Lrc;
 
 pub struct TerminatingConjunctions {
@@ -53,13 +53,19 @@ impl PatternLinter for TerminatingConjunctions {
         self.pattern.as_ref()
     }
 
-    fn match_to_lint(&self, matched_tokens: &[crate::Token], source: &[char]) -> Lint {
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[crate::Token],
+        source: &[char],
+        _captures: &Captures,
+    ) -> Lint {
         let word_span = matched_tokens[0].span;
         let word = word_span.get_content_string(source);
 
         Lint {
             span: word_span,
             lint_kind: LintKind::Miscellaneous,
+            rule_id: "TerminatingConjunctions".to_string(),
             suggestions: vec![],
             message: format!(
                 "Subordinating conjunctions like “{word}” should not appear at the end of a \
@@ -68,6 +74,19 @@ impl PatternLinter for TerminatingConjunctions {
             priority: 63,
         }
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Terminating Conjunctions",
+            "Looks for subordinating conjunctions at the end of a clause.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Subordinating conjunctions like “than” introduce a clause, so a clause that ends with one is usually missing something.",
+        )
+        .with_good_example("Cookies and milk.")
+        .with_bad_example("More often than, we cannot foresee that of our community.")
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+
+use super::phrase_rules::PhraseRules;
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
+use crate::{Document, TokenStringExt};
+
+fn default_true() -> bool {
+    true
+}
+
+/// A preferred term from a project glossary.
+///
+/// Glossaries are usually loaded from a JSON file containing an array of
+/// entries:
+///
+/// ```json
+/// [
+///   { "term": "GitHub", "variants": ["Git Hub"] },
+///   {
+///     "term": "sign in",
+///     "variants": ["log in", "login to"],
+///     "case_sensitive": false,
+///     "note": "Our UI says “Sign in”."
+///   }
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlossaryEntry {
+    /// The preferred term, as it should be written.
+    /// It may span several words.
+    pub term: String,
+    /// Banned synonyms or misspellings of the term, which are matched
+    /// regardless of case.
+    #[serde(default)]
+    pub variants: Vec<String>,
+    /// Whether the term must be written with exactly the casing given, so
+    /// that “Github” is flagged for “GitHub”.
+    /// The first letter may still be capitalized at the start of a sentence.
+    #[serde(default = "default_true")]
+    pub case_sensitive: bool,
+    /// An explanation to show alongside the suggestion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Options for the [`Terminology`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct TerminologyConfig {
+    pub glossary: Vec<GlossaryEntry>,
+}
+
+/// Something that should be written as a glossary term instead.
+struct TermRule {
+    /// Whether the rule looks for incorrect casing of the term itself, rather
+    /// than a banned variant.
+    is_casing: bool,
+    term: Vec<char>,
+    /// Whether the casing of the term may be adapted to the text it replaces,
+    /// like at the start of a sentence.
+    match_case: bool,
+    note: Option<String>,
+}
+
+fn capitalize(term: &[char]) -> Vec<char> {
+    match term.split_first() {
+        Some((first, rest)) => first.to_uppercase().chain(rest.iter().copied()).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Enforces the preferred spelling and casing of the terms in a project
+/// glossary, like “GitHub” or “JavaScript”.
+pub struct Terminology {
+    rules: PhraseRules<TermRule>,
+}
+
+impl Terminology {
+    pub fn new(config: TerminologyConfig) -> Self {
+        let mut rules = PhraseRules::default();
+
+        for entry in config.glossary {
+            let term: Vec<char> = entry.term.trim().chars().collect();
+
+            if entry.case_sensitive {
+                rules.push(
+                    &entry.term,
+                    false,
+                    TermRule {
+                        is_casing: true,
+                        term: term.clone(),
+                        match_case: false,
+                        note: entry.note.clone(),
+                    },
+                );
+            }
+
+            for variant in &entry.variants {
+                // Different casings of the term are already covered above.
+                if entry.case_sensitive && variant.trim().eq_ignore_ascii_case(entry.term.trim()) {
+                    continue;
+                }
+
+                rules.push(
+                    variant,
+                    false,
+                    TermRule {
+                        is_casing: false,
+                        term: term.clone(),
+                        match_case: !entry.case_sensitive,
+                        note: entry.note.clone(),
+                    },
+                );
+            }
+        }
+
+        Self { rules }
+    }
+}
+
+impl Default for Terminology {
+    fn default() -> Self {
+        Self::new(TerminologyConfig::default())
+    }
+}
+
+impl Linter for Terminology {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        let tokens = document.get_tokens();
+
+        for (found, rule) in self.rules.find_all(document) {
+            let starts_sentence = tokens[..found.start]
+                .iter()
+                .rev()
+                .find(|t| !t.kind.is_whitespace())
+                .is_none_or(|t| t.is_sentence_terminator());
+
+            let span = tokens[found].span().unwrap();
+            let content = document.get_span_content(span);
+
+            // Lowercase terms are capitalized at the start of a sentence.
+            let expected = if starts_sentence {
+                capitalize(&rule.term)
+            } else {
+                rule.term.clone()
+            };
+
+            if rule.is_casing && (content == rule.term.as_slice() || content == expected) {
+                continue;
+            }
+
+            let term: String = expected.iter().collect();
+
+            let mut message = format!(
+                "Use “{term}” instead of “{}”.",
+                document.get_span_content_str(span)
+            );
+
+            if let Some(note) = &rule.note {
+                message.push(' ');
+                message.push_str(note);
+            }
+
+            lints.push(Lint {
+                span,
+                lint_kind: if rule.is_casing {
+                    LintKind::Capitalization
+                } else {
+                    LintKind::Miscellaneous
+                },
+                rule_id: "Terminology".to_string(),
+                suggestions: vec![if rule.match_case {
+                    Suggestion::replace_with_match_case(rule.term.clone(), content)
+                } else {
+                    Suggestion::ReplaceWith(expected)
+                }],
+                message,
+                priority: 31,
+            })
+        }
+
+        lints
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Terminology",
+            "Enforces the terms in a project glossary.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Flags terms from the `glossary` that are written with the wrong casing, like “Github” for “GitHub”, along with any banned variants of them. Without a glossary, this rule does nothing.",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Terminology;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::Linter;
+    use crate::Document;
+
+    const SIGN_IN: &str = r#"{ "glossary": [
+        { "term": "sign in", "variants": ["log in"], "case_sensitive": false }
+    ] }"#;
+
+    #[test]
+    fn fixes_casing() {
+        assert_suggestion_result(
+            "We host the code on Github and write it in javascript.",
+            Terminology::new(
+                serde_json::from_str(
+                    r#"{ "glossary": [{ "term": "GitHub" }, { "term": "JavaScript" }] }"#,
+                )
+                .unwrap(),
+            ),
+            "We host the code on GitHub and write it in JavaScript.",
+        );
+    }
+
+    #[test]
+    fn replaces_variants() {
+        assert_suggestion_result(
+            "Install the Harper language server with cargo.",
+            Terminology::new(
+                serde_json::from_str(
+                    r#"{ "glossary": [
+                        { "term": "harper-ls", "variants": ["Harper language server"] }
+                    ] }"#,
+                )
+                .unwrap(),
+            ),
+            "Install the harper-ls with cargo.",
+        );
+    }
+
+    #[test]
+    fn matches_multi_token_terms() {
+        assert_suggestion_result(
+            "Start Harper-LS first.",
+            Terminology::new(
+                serde_json::from_str(r#"{ "glossary": [{ "term": "harper-ls" }] }"#).unwrap(),
+            ),
+            "Start harper-ls first.",
+        );
+    }
+
+    #[test]
+    fn ignores_casing_when_asked() {
+        let linter = Terminology::new(serde_json::from_str(SIGN_IN).unwrap());
+
+        assert_lint_count("Sign in, then log in again.", linter, 1);
+    }
+
+    #[test]
+    fn adapts_case_when_ignoring_casing() {
+        assert_suggestion_result(
+            "Log in to continue.",
+            Terminology::new(serde_json::from_str(SIGN_IN).unwrap()),
+            "Sign in to continue.",
+        );
+    }
+
+    #[test]
+    fn includes_note() {
+        let mut linter = Terminology::new(
+            serde_json::from_str(
+                r#"{ "glossary": [
+                    { "term": "sign in", "variants": ["log in"], "note": "Match the UI." }
+                ] }"#,
+            )
+            .unwrap(),
+        );
+        let document = Document::new_plain_english_curated("Please log in.");
+        let lints = linter.lint(&document);
+
+        assert_eq!(
+            lints[0].message,
+            "Use “sign in” instead of “log in”. Match the UI."
+        );
+    }
+
+    #[test]
+    fn allows_capitalized_terms_at_sentence_start() {
+        assert_lint_count(
+            "Harper-ls is fast. We like harper-ls.",
+            Terminology::new(
+                serde_json::from_str(r#"{ "glossary": [{ "term": "harper-ls" }] }"#).unwrap(),
+            ),
+            0,
+        );
+    }
+
+    #[test]
+    fn capitalizes_terms_at_sentence_start() {
+        assert_suggestion_result(
+            "HARPER-LS is fast. Harper language server is small.",
+            Terminology::new(
+                serde_json::from_str(
+                    r#"{ "glossary": [
+                        { "term": "harper-ls", "variants": ["Harper language server"] }
+                    ] }"#,
+                )
+                .unwrap(),
+            ),
+            "Harper-ls is fast. Harper-ls is small.",
+        );
+    }
+
+    #[test]
+    fn does_nothing_without_glossary() {
+        assert_lint_count("Github and javascript.", Terminology::default(), 0);
+    }
+}
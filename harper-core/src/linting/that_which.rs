@@ -1,11 +1,11 @@
 use itertools::Itertools;
 
 use crate::{
-    patterns::{Pattern, SequencePattern, WordPatternGroup},
+    patterns::{Captures, Pattern, SequencePattern, WordPatternGroup},
     Lrc, Token, TokenStringExt,
 };
 
-use super::{Lint, LintKind, PatternLinter, Suggestion};
+use super::{Lint, LintKind, LinterDescription, PatternLinter, Suggestion};
 
 pub struct ThatWhich {
     pattern: Box<dyn Pattern>,
@@ -36,7 +36,12 @@ impl PatternLinter for ThatWhich {
         self.pattern.as_ref()
     }
 
-    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint {
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        source: &[char],
+        _captures: &Captures,
+    ) -> Lint {
         let suggestion = format!(
             "{} which",
             matched_tokens[0]
@@ -51,11 +56,25 @@ impl PatternLinter for ThatWhich {
         Lint {
             span: matched_tokens.span().unwrap(),
             lint_kind: LintKind::Repetition,
+            rule_id: "ThatWhich".to_string(),
             suggestions: vec![Suggestion::ReplaceWith(suggestion)],
             message: "“that that” sometimes means “that which”, which is clearer.".to_string(),
             priority: 126,
         }
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "That Which",
+            "Suggests “that which” in place of “that that”.",
+            LintKind::Repetition,
+        )
+        .with_long_description(
+            "“That that” is grammatical, but often reads as a mistake. “That which” means the same and is clearer.",
+        )
+        .with_good_example("To reiterate, that which is cool is not uncool.")
+        .with_bad_example("To reiterate, that that is cool is not uncool.")
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Linter, LinterDescription};
 use crate::document::Document;
 use crate::{Punctuation, Quote, TokenKind};
 
@@ -16,6 +16,7 @@ impl Linter for UnclosedQuotes {
                 lints.push(Lint {
                     span: token.span,
                     lint_kind: LintKind::Formatting,
+                    rule_id: "UnclosedQuotes".to_string(),
                     suggestions: vec![],
                     message: "This quote has no termination.".to_string(),
                     priority: 255,
@@ -25,4 +26,17 @@ impl Linter for UnclosedQuotes {
 
         lints
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Unclosed Quotes",
+            "Looks for quotation marks that are never closed.",
+            LintKind::Formatting,
+        )
+        .with_long_description(
+            "Every opening quotation mark should have a matching closing one. Otherwise, it is unclear where the quotation ends.",
+        )
+        .with_good_example("He said “hello” to me.")
+        .with_bad_example("He said “hello to me.")
+    }
 }
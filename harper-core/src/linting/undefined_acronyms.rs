@@ -0,0 +1,335 @@
+use std::sync::Arc;
+
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter, LinterDescription};
+use crate::{Dictionary, Document, FstDictionary, Token};
+
+/// Acronyms that nearly every reader knows, so they don't need to be defined.
+const UNIVERSAL: [&str; 24] = [
+    "AM", "API", "CEO", "CPU", "CSS", "EU", "FAQ", "GPU", "HTML", "HTTP", "HTTPS", "ID", "JSON",
+    "OK", "PDF", "PM", "RAM", "SQL", "TV", "UI", "UK", "URL", "USA", "USB",
+];
+
+/// Options for the [`UndefinedAcronyms`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct UndefinedAcronymsConfig {
+    /// Acronyms that don't need to be defined, in addition to universal ones
+    /// like “HTML” and “URL”.
+    pub allowed: Vec<String>,
+}
+
+/// Looks for acronyms that are used before they are spelled out, like “LSP”
+/// before “Language Server Protocol (LSP)”.
+pub struct UndefinedAcronyms {
+    allowed: HashSet<String>,
+    dictionary: Arc<FstDictionary>,
+}
+
+impl UndefinedAcronyms {
+    pub fn new(config: UndefinedAcronymsConfig) -> Self {
+        let allowed = UNIVERSAL
+            .iter()
+            .map(|acronym| acronym.to_string())
+            .chain(
+                config
+                    .allowed
+                    .iter()
+                    .filter_map(|a| acronym_of(&a.chars().collect::<Vec<_>>())),
+            )
+            .collect();
+
+        Self {
+            allowed,
+            dictionary: FstDictionary::curated(),
+        }
+    }
+
+    /// Get the acronym a token represents, if it is one that needs to be
+    /// defined.
+    fn acronym_at(&self, token: &Token, source: &[char]) -> Option<String> {
+        if !token.kind.is_word() {
+            return None;
+        }
+
+        let acronym = acronym_of(token.span.get_content(source))?;
+
+        // Words written in all caps for emphasis, like “NOTE”.
+        let lower: Vec<char> = acronym.to_lowercase().chars().collect();
+
+        if self.allowed.contains(&acronym) || self.dictionary.contains_word(&lower) {
+            return None;
+        }
+
+        Some(acronym)
+    }
+}
+
+impl Default for UndefinedAcronyms {
+    fn default() -> Self {
+        Self::new(UndefinedAcronymsConfig::default())
+    }
+}
+
+impl Linter for UndefinedAcronyms {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let tokens = document.get_tokens();
+        let source = document.get_source();
+
+        let mut definitions = HashMap::new();
+
+        for (index, token) in tokens.iter().enumerate() {
+            if let Some(acronym) = self.acronym_at(token, source) {
+                if is_definition(tokens, index, &acronym, source) {
+                    definitions.entry(acronym).or_insert(index);
+                }
+            }
+        }
+
+        let mut lints = Vec::new();
+        let mut seen = HashSet::new();
+
+        for (index, token) in tokens.iter().enumerate() {
+            let Some(acronym) = self.acronym_at(token, source) else {
+                continue;
+            };
+
+            if !seen.insert(acronym.clone()) {
+                continue;
+            }
+
+            let message = match definitions.get(&acronym) {
+                Some(definition) if *definition <= index => continue,
+                Some(_) => format!("“{acronym}” is used before it is defined."),
+                None => format!(
+                    "“{acronym}” is never defined. Spell it out the first time it is used, like “Full Name ({acronym})”."
+                ),
+            };
+
+            lints.push(Lint {
+                span: token.span,
+                lint_kind: LintKind::Readability,
+                rule_id: "UndefinedAcronyms".to_string(),
+                message,
+                priority: 127,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Undefined Acronyms",
+            "Checks that acronyms are spelled out before they are used.",
+            LintKind::Readability,
+        )
+        .with_long_description(
+            "Readers may not know what an acronym stands for, so it should be defined the first time it is used, either as “Language Server Protocol (LSP)” or “LSP (Language Server Protocol)”. Universal acronyms like “HTML” and “URL” are allowed.",
+        )
+        .with_good_example(
+            "Harper implements the Language Server Protocol (LSP). The LSP is supported by most editors.",
+        )
+        .with_bad_example("Harper implements the LSP, which most editors support.")
+    }
+}
+
+/// Get the acronym a word represents, if it is one.
+///
+/// Dotted initialisms, like “N.S.A.”, are condensed into a single token by
+/// the [`Document`], so they are treated like “NSA”.
+/// Plurals, like “APIs”, are treated like their singular form.
+fn acronym_of(word: &[char]) -> Option<String> {
+    let word = match word {
+        [rest @ .., 's'] => rest,
+        _ => word,
+    };
+
+    let letters: String = word.iter().filter(|c| **c != '.').collect();
+
+    let is_acronym = letters.chars().filter(|c| c.is_uppercase()).count() >= 2
+        && letters
+            .chars()
+            .all(|c| c.is_uppercase() || c.is_ascii_digit());
+
+    // Roman numerals, like “III” or “XIV”.
+    let is_numeral = letters.chars().all(|c| matches!(c, 'I' | 'V' | 'X'));
+
+    (is_acronym && !is_numeral).then_some(letters)
+}
+
+/// Whether the acronym at an index is being defined, either as
+/// “Full Name (FN)” or “FN (Full Name)”.
+fn is_definition(tokens: &[Token], index: usize, acronym: &str, source: &[char]) -> bool {
+    let is_name_part =
+        |t: &&Token| t.kind.is_word() || t.kind.is_whitespace() || t.kind.is_hyphen();
+
+    // “Full Name (FN)”
+    if index > 0
+        && tokens[index - 1].kind.is_open_round()
+        && tokens
+            .get(index + 1)
+            .is_some_and(|t| t.kind.is_close_round())
+    {
+        let name = tokens[..index - 1].iter().rev().take_while(is_name_part);
+
+        return spells(name, acronym, source);
+    }
+
+    // “FN (Full Name)”
+    let mut open = index + 1;
+
+    if tokens.get(open).is_some_and(|t| t.kind.is_whitespace()) {
+        open += 1;
+    }
+
+    if !tokens.get(open).is_some_and(|t| t.kind.is_open_round()) {
+        return false;
+    }
+
+    let name: Vec<&Token> = tokens[open + 1..].iter().take_while(is_name_part).collect();
+
+    let is_closed = tokens
+        .get(open + 1 + name.len())
+        .is_some_and(|t| t.kind.is_close_round());
+
+    is_closed && spells(name.into_iter().rev(), acronym, source)
+}
+
+/// Whether the initials of a name, given from its last word to its first,
+/// spell out an acronym.
+///
+/// Lowercase words that don't fit, like “of” or “the”, are skipped.
+/// Words with capitals inside them, like “JavaScript”, may stand for several
+/// letters.
+fn spells<'a>(name: impl Iterator<Item = &'a Token>, acronym: &str, source: &[char]) -> bool {
+    let acronym: Vec<char> = acronym.chars().collect();
+    let mut remaining = acronym.as_slice();
+
+    for word in name.filter(|t| t.kind.is_word()) {
+        let Some(last) = remaining.last() else {
+            break;
+        };
+
+        let content = word.span.get_content(source);
+        let first = content[0];
+
+        let initials: Vec<char> = content
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| *i == 0 || c.is_uppercase())
+            .flat_map(|(_, c)| c.to_uppercase())
+            .collect();
+
+        if remaining.ends_with(&initials) {
+            remaining = &remaining[..remaining.len() - initials.len()];
+        } else if first.to_uppercase().eq([*last]) {
+            remaining = &remaining[..remaining.len() - 1];
+        } else if !first.is_lowercase() {
+            return false;
+        }
+    }
+
+    remaining.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UndefinedAcronyms, UndefinedAcronymsConfig};
+    use crate::linting::tests::assert_lint_count;
+    use crate::linting::Linter;
+    use crate::Document;
+
+    #[test]
+    fn flags_undefined_acronym_once() {
+        assert_lint_count(
+            "The LSP is fast. The LSP is everywhere.",
+            UndefinedAcronyms::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn allows_name_then_acronym() {
+        assert_lint_count(
+            "The Language Server Protocol (LSP) is fast. The LSP is everywhere.",
+            UndefinedAcronyms::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_acronym_then_name() {
+        assert_lint_count(
+            "The LSP (Language Server Protocol) is fast. LSPs are everywhere.",
+            UndefinedAcronyms::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_small_words_in_names() {
+        assert_lint_count(
+            "The Bureau of Labor Statistics (BLS) publishes data.",
+            UndefinedAcronyms::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn needs_a_matching_name() {
+        assert_lint_count("The LSP (really) works.", UndefinedAcronyms::default(), 1);
+
+        let document = Document::new_markdown_curated("Run the CLI tool (HCL).");
+        let lints = UndefinedAcronyms::default().lint(&document);
+
+        assert!(lints
+            .iter()
+            .any(|lint| lint.message.starts_with("“HCL” is never defined.")));
+    }
+
+    #[test]
+    fn flags_use_before_definition() {
+        let document = Document::new_markdown_curated(
+            "The LSP is fast. The Language Server Protocol (LSP) is everywhere.",
+        );
+        let lints = UndefinedAcronyms::default().lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].message, "“LSP” is used before it is defined.");
+    }
+
+    #[test]
+    fn handles_dotted_initialisms() {
+        assert_lint_count(
+            "The National Security Agency (NSA) was founded in 1952. The N.S.A. is large.",
+            UndefinedAcronyms::default(),
+            0,
+        );
+        assert_lint_count("The N.S.A. is large.", UndefinedAcronyms::default(), 1);
+    }
+
+    #[test]
+    fn allows_universal_and_configured_acronyms() {
+        assert_lint_count("Send the HTML to the URL.", UndefinedAcronyms::default(), 0);
+        assert_lint_count(
+            "Install the SDK.",
+            UndefinedAcronyms::new(UndefinedAcronymsConfig {
+                allowed: vec!["SDK".to_string()],
+            }),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_emphasis_and_numerals() {
+        assert_lint_count(
+            "NOTE: this is IMPORTANT for World War II.",
+            UndefinedAcronyms::default(),
+            0,
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use crate::linting::{LintKind, PatternLinter, Suggestion};
-use crate::patterns::{EitherPattern, Invert, Pattern, SequencePattern, WordPatternGroup};
+use crate::linting::{LintKind, LinterDescription, PatternLinter, Suggestion};
+use crate::patterns::{
+    Captures, EitherPattern, Invert, Pattern, SequencePattern, WordPatternGroup,
+};
 use crate::{Lint, Lrc, Token};
 
 // Looks for places where the genitive case _isn't_ being used, and should be.
@@ -14,11 +16,11 @@ impl UseGenitive {
             EitherPattern::new(vec![
                     Box::new(
                         SequencePattern::default()
-                            .then_one_or_more_adjectives()
+                            .then_one_or_more(Box::new(is_tagged_adjective))
                             .then_whitespace()
-                            .then_noun(),
+                            .then(Box::new(is_tagged_noun)),
                     ),
-                    Box::new(SequencePattern::default().then_noun()),
+                    Box::new(SequencePattern::default().then(Box::new(is_tagged_noun))),
                 ]),
         )));
 
@@ -31,7 +33,10 @@ impl UseGenitive {
                 word,
                 Box::new(
                     SequencePattern::default()
-                        .then_exact_word(word)
+                        .then_capture(
+                            "trigger",
+                            Box::new(SequencePattern::default().then_exact_word(word)),
+                        )
                         .then(Box::new(environment.clone())),
                 ),
             )
@@ -53,20 +58,54 @@ impl UseGenitive {
     }
 }
 
+/// Whether the word plays the part of a noun in its sentence.
+fn is_tagged_noun(token: &Token, _source: &[char]) -> bool {
+    token.kind.as_word().is_some_and(|m| m.narrowed().is_noun())
+}
+
+/// Whether the word plays the part of an adjective in its sentence.
+fn is_tagged_adjective(token: &Token, _source: &[char]) -> bool {
+    token
+        .kind
+        .as_word()
+        .is_some_and(|m| m.narrowed().is_adjective())
+}
+
 impl PatternLinter for UseGenitive {
     fn pattern(&self) -> &dyn crate::patterns::Pattern {
         self.pattern.as_ref()
     }
 
-    fn match_to_lint(&self, matched_tokens: &[Token], _source: &[char]) -> Lint {
+    fn match_to_lint(
+        &self,
+        matched_tokens: &[Token],
+        _source: &[char],
+        captures: &Captures,
+    ) -> Lint {
+        let trigger = captures.get_tokens("trigger", matched_tokens).unwrap();
+
         Lint {
-            span: matched_tokens[2].span,
+            span: trigger[0].span,
             lint_kind: LintKind::Miscellaneous,
+            rule_id: "UseGenitive".to_string(),
             suggestions: vec![Suggestion::ReplaceWith(vec!['t', 'h', 'e', 'i', 'r'])],
             message: "Use the genitive case.".to_string(),
             priority: 31,
         }
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Use Genitive",
+            "Suggests the genitive case where it is more appropriate.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Words like “there” and “they're” are often confused with the possessive “their”.",
+        )
+        .with_good_example("There is a cute cat sitting on the chair at home.")
+        .with_bad_example("The students received they're test results today.")
+    }
 }
 
 impl Default for UseGenitive {
@@ -163,6 +202,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn allows_adverbs_that_can_be_nouns() {
+        assert_lint_count("We drove there fast.", UseGenitive::default(), 0);
+        assert_lint_count("He ran there first.", UseGenitive::default(), 0);
+        assert_lint_count("We camped there overnight.", UseGenitive::default(), 0);
+    }
+
     #[test]
     fn allows_faster_at_running() {
         assert_lint_count(
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+use super::phrase_rules::PhraseRules;
+use super::{Lint, LintKind, Linter, LinterDescription, Replacement, Suggestion};
+use crate::{Document, Span, TokenStringExt};
+
+/// Wordy phrases and their concise replacements.
+/// Phrases that can simply be left out have an empty replacement.
+const PHRASES: &[(&str, &str)] = &[
+    ("in order to", "to"),
+    ("at this point in time", "now"),
+    ("at the present time", "now"),
+    ("each and every", "each"),
+    ("first and foremost", "first"),
+    ("absolutely essential", "essential"),
+    ("due to the fact that", "because"),
+    ("in spite of the fact that", "although"),
+    ("despite the fact that", "although"),
+    ("for the purpose of", "for"),
+    ("in the event that", "if"),
+    ("in the near future", "soon"),
+    ("a large number of", "many"),
+    ("the vast majority of", "most"),
+    ("has the ability to", "can"),
+    ("have the ability to", "can"),
+    ("in close proximity to", "near"),
+    ("with regard to", "about"),
+    ("in regard to", "about"),
+    ("with respect to", "about"),
+    ("prior to", "before"),
+    ("subsequent to", "after"),
+    ("until such time as", "until"),
+    ("on a daily basis", "daily"),
+    ("in a timely manner", "promptly"),
+    ("take into consideration", "consider"),
+    ("end result", "result"),
+    ("final outcome", "outcome"),
+    ("past history", "history"),
+    ("advance planning", "planning"),
+    ("basic fundamentals", "fundamentals"),
+    ("completely eliminate", "eliminate"),
+    ("unexpected surprise", "surprise"),
+    ("free gift", "gift"),
+    ("future plans", "plans"),
+    ("join together", "join"),
+    ("revert back", "revert"),
+    ("repeat again", "repeat"),
+    ("still remains", "remains"),
+    ("it should be noted that", ""),
+    ("it is important to note that", ""),
+    ("it goes without saying that", ""),
+];
+
+/// A wordy phrase for the [`Wordiness`] linter to look for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordyPhrase {
+    /// The phrase to look for, which is matched regardless of case.
+    pub phrase: String,
+    /// The concise text to suggest in its place.
+    /// If empty, the phrase will be suggested for removal.
+    #[serde(default)]
+    pub replace_with: String,
+}
+
+/// Options for the [`Wordiness`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct WordinessConfig {
+    /// Phrases to look for in addition to the built-in ones.
+    pub phrases: Vec<WordyPhrase>,
+}
+
+/// Looks for wordy and redundant phrases, like “in order to” or “each and
+/// every”, that could be said more concisely.
+pub struct Wordiness {
+    /// The phrases to look for, along with their replacements.
+    phrases: PhraseRules<Vec<char>>,
+}
+
+impl Wordiness {
+    pub fn new(config: WordinessConfig) -> Self {
+        let mut phrases = PhraseRules::default();
+
+        for (phrase, replacement) in PHRASES {
+            phrases.push(phrase, false, replacement.chars().collect());
+        }
+
+        for p in &config.phrases {
+            phrases.push(&p.phrase, false, p.replace_with.chars().collect());
+        }
+
+        Self { phrases }
+    }
+}
+
+impl Default for Wordiness {
+    fn default() -> Self {
+        Self::new(WordinessConfig::default())
+    }
+}
+
+impl Linter for Wordiness {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let tokens = document.get_tokens();
+        let mut lints = Vec::new();
+
+        for (found, replacement) in self.phrases.find_all(document) {
+            let span = tokens[found.clone()].span().unwrap();
+            let content = document.get_span_content(span);
+            let phrase = document.get_span_content_str(span);
+
+            let (suggestion, message) = if replacement.is_empty() {
+                // Phrases that can be left out take the whitespace after them
+                // along, and the next word may need to be capitalized.
+                let (Some(space), Some(next_word)) =
+                    (tokens.get(found.end), tokens.get(found.end + 1))
+                else {
+                    continue;
+                };
+
+                if !space.kind.is_whitespace() || !next_word.kind.is_word() {
+                    continue;
+                }
+
+                let next_word = next_word.span;
+                let mut edits = vec![Replacement {
+                    span: Span::new(span.start, next_word.start),
+                    text: Vec::new(),
+                }];
+
+                // Keep the start of the sentence capitalized.
+                let next_first = document.get_span_content(next_word)[0];
+
+                if content[0].is_uppercase() && next_first.is_lowercase() {
+                    edits.push(Replacement {
+                        span: next_word.with_len(1),
+                        text: next_first.to_uppercase().collect(),
+                    });
+                }
+
+                (
+                    Suggestion::Edits(edits),
+                    format!("“{phrase}” can usually be left out."),
+                )
+            } else {
+                (
+                    Suggestion::replace_with_match_case(replacement.clone(), content),
+                    format!(
+                        "“{phrase}” can be shortened to “{}”.",
+                        replacement.iter().collect::<String>()
+                    ),
+                )
+            };
+
+            lints.push(Lint {
+                span,
+                lint_kind: LintKind::Readability,
+                rule_id: "Wordiness".to_string(),
+                suggestions: vec![suggestion],
+                message,
+                priority: 63,
+            });
+        }
+
+        lints
+    }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Wordiness",
+            "Looks for wordy and redundant phrases that could be said more concisely.",
+            LintKind::Readability,
+        )
+        .with_long_description(
+            "Phrases like “in order to” and “at this point in time” make text longer without making it clearer, and phrases like “each and every” or “past history” say the same thing twice. This rule suggests a shorter alternative for each, or that it be left out entirely.",
+        )
+        .with_good_example("We need each of them to ship the release now.")
+        .with_bad_example(
+            "In order to ship the release at this point in time, we need each and every one of them.",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Wordiness, WordinessConfig};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn shortens_phrases() {
+        assert_suggestion_result(
+            "In order to build it, we need each and every file.",
+            Wordiness::default(),
+            "To build it, we need each file.",
+        );
+    }
+
+    #[test]
+    fn removes_redundant_words() {
+        assert_suggestion_result(
+            "This step is absolutely essential.",
+            Wordiness::default(),
+            "This step is essential.",
+        );
+    }
+
+    #[test]
+    fn removes_filler_phrases() {
+        assert_suggestion_result(
+            "It should be noted that the build is slow.",
+            Wordiness::default(),
+            "The build is slow.",
+        );
+        assert_suggestion_result(
+            "Sadly, it goes without saying that the build is slow.",
+            Wordiness::default(),
+            "Sadly, the build is slow.",
+        );
+    }
+
+    #[test]
+    fn allows_concise_text() {
+        assert_lint_count(
+            "We need each file to build it now, in order.",
+            Wordiness::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn uses_configured_phrases() {
+        let config: WordinessConfig = serde_json::from_str(
+            r#"{ "phrases": [{ "phrase": "make use of", "replace_with": "use" }] }"#,
+        )
+        .unwrap();
+
+        assert_suggestion_result(
+            "We make use of the cache.",
+            Wordiness::new(config),
+            "We use the cache.",
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Lint, Linter, Suggestion};
+use super::{Lint, LintKind, Linter, LinterDescription, Suggestion};
 use crate::document::Document;
 use crate::{Token, TokenStringExt};
 
@@ -13,6 +13,19 @@ impl Linter for WrongQuotes {
             .filter_map(|(quote_idx, quote_token)| lint_quote(document, quote_idx, quote_token))
             .collect()
     }
+
+    fn describe(&self) -> LinterDescription {
+        LinterDescription::new(
+            "Wrong Quotes",
+            "Suggests better-formatted quote characters.",
+            LintKind::Miscellaneous,
+        )
+        .with_long_description(
+            "Typographic (curly) quotes are easier to read than straight ones, and make it clear which side of the quotation they belong to.",
+        )
+        .with_good_example("He said “hello” to me.")
+        .with_bad_example("He said \"hello\" to me.")
+    }
 }
 
 fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Option<Lint> {
@@ -28,6 +41,7 @@ fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Opti
     if quote_char != should_be {
         Some(Lint {
             span: quote_token.span,
+            rule_id: "WrongQuotes".to_string(),
             suggestions: vec![Suggestion::ReplaceWith(vec![should_be])],
             message: "Use the better-formatted quote character.".to_string(),
             ..Default::default()
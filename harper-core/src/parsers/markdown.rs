@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use super::{Parser, PlainEnglish};
-use crate::{Span, Token, TokenKind, TokenStringExt, VecExt};
+use crate::{Span, Token, TokenContext, TokenKind, TokenStringExt, VecExt};
 
 /// A parser that wraps the [`PlainEnglish`] parser that allows one to parse
 /// CommonMark files.
@@ -115,6 +115,28 @@ impl Markdown {
 
         tokens.remove_indices(to_remove);
     }
+
+    /// Describe where text sits in the document, given the tags that are
+    /// currently open around it.
+    fn context_of(stack: &[pulldown_cmark::Tag]) -> TokenContext {
+        use pulldown_cmark::Tag;
+
+        let mut context = TokenContext::default();
+
+        for tag in stack {
+            match tag {
+                Tag::Heading { level, .. } => context.heading_level = Some(*level as u8),
+                Tag::Item => context.list_item = true,
+                Tag::BlockQuote(_) => context.block_quote = true,
+                Tag::TableCell => context.table_cell = true,
+                Tag::Link { .. } => context.link_text = true,
+                Tag::Emphasis | Tag::Strong => context.emphasis = true,
+                _ => (),
+            }
+        }
+
+        context
+    }
 }
 
 impl Parser for Markdown {
@@ -145,23 +167,28 @@ impl Parser for Markdown {
                 traversed_bytes = range.start;
             }
 
+            let context = Self::context_of(&stack);
+
             match event {
                 pulldown_cmark::Event::SoftBreak => {
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, 1),
                         kind: TokenKind::Newline(1),
+                        context,
                     });
                 }
                 pulldown_cmark::Event::HardBreak => {
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, 1),
                         kind: TokenKind::Newline(2),
+                        context,
                     });
                 }
                 pulldown_cmark::Event::Start(pulldown_cmark::Tag::List(v)) => {
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, 0),
                         kind: TokenKind::Newline(2),
+                        context,
                     });
                     stack.push(pulldown_cmark::Tag::List(v));
                 }
@@ -173,6 +200,7 @@ impl Parser for Markdown {
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, 0),
                         kind: TokenKind::Newline(2),
+                        context,
                     });
                     stack.pop();
                 }
@@ -187,6 +215,7 @@ impl Parser for Markdown {
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, chunk_len),
                         kind: TokenKind::Unlintable,
+                        context,
                     });
                 }
                 pulldown_cmark::Event::Text(text) => {
@@ -199,6 +228,7 @@ impl Parser for Markdown {
                             tokens.push(Token {
                                 span: Span::new_with_len(traversed_chars, text.chars().count()),
                                 kind: TokenKind::Unlintable,
+                                context,
                             });
                             continue;
                         }
@@ -219,9 +249,10 @@ impl Parser for Markdown {
                     let mut new_tokens =
                         english_parser.parse(&source[traversed_chars..traversed_chars + chunk_len]);
 
-                    new_tokens
-                        .iter_mut()
-                        .for_each(|token| token.span.push_by(traversed_chars));
+                    new_tokens.iter_mut().for_each(|token| {
+                        token.span.push_by(traversed_chars);
+                        token.context = context;
+                    });
 
                     tokens.append(&mut new_tokens);
                 }
@@ -232,6 +263,7 @@ impl Parser for Markdown {
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, size),
                         kind: TokenKind::Unlintable,
+                        context,
                     });
                 }
                 _ => (),
@@ -260,7 +292,13 @@ impl Parser for Markdown {
 mod tests {
     use super::super::StrParser;
     use super::Markdown;
-    use crate::{Punctuation, TokenKind, TokenStringExt};
+    use crate::{Document, Punctuation, Token, TokenKind, TokenStringExt};
+
+    fn contents<'a>(document: &Document, slices: impl Iterator<Item = &'a [Token]>) -> Vec<String> {
+        slices
+            .map(|slice| document.get_span_content_str(slice.span().unwrap()))
+            .collect()
+    }
 
     #[test]
     fn survives_emojis() {
@@ -369,4 +407,88 @@ mod tests {
         let tokens = Markdown.parse_str(source);
         assert_eq!(tokens.iter_unlintables().count(), 1);
     }
+
+    #[test]
+    fn marks_headings() {
+        let document =
+            Document::new_markdown_curated("# The Title\n\nSome text.\n\n### A Section\n");
+
+        assert_eq!(
+            contents(&document, document.iter_headings()),
+            vec!["The Title", "A Section"]
+        );
+
+        let levels: Vec<_> = document
+            .iter_words()
+            .map(|word| word.context.heading_level)
+            .collect();
+        assert_eq!(levels, vec![Some(1), Some(1), None, None, Some(3), Some(3)]);
+    }
+
+    #[test]
+    fn marks_list_items() {
+        let document =
+            Document::new_markdown_curated("Intro text.\n\n- first item\n- second item\n");
+
+        assert_eq!(
+            contents(&document, document.iter_list_items()),
+            vec!["first item", "second item"]
+        );
+    }
+
+    #[test]
+    fn marks_nested_list_items() {
+        let document = Document::new_markdown_curated("- outer\n  - inner\n- last\n");
+
+        assert_eq!(
+            contents(&document, document.iter_list_items()),
+            vec!["outer", "inner", "last"]
+        );
+    }
+
+    #[test]
+    fn marks_block_quotes() {
+        let document = Document::new_markdown_curated("> Quoted text.\n\nNormal text.");
+
+        assert_eq!(
+            contents(&document, document.iter_block_quotes()),
+            vec!["Quoted text."]
+        );
+    }
+
+    #[test]
+    fn marks_table_cells() {
+        let document =
+            Document::new_markdown_curated("| Name | Value |\n| --- | --- |\n| first | second |\n");
+
+        assert_eq!(
+            contents(&document, document.iter_table_cells()),
+            vec!["Name", "Value", "first", "second"]
+        );
+    }
+
+    #[test]
+    fn marks_inline_elements() {
+        let document = Document::new_markdown_curated(
+            "Read [the manual](https://example.com) *before* you **start**.",
+        );
+
+        assert_eq!(
+            contents(&document, document.iter_link_texts()),
+            vec!["the manual"]
+        );
+        assert_eq!(
+            contents(&document, document.iter_emphases()),
+            vec!["before", "start"]
+        );
+    }
+
+    #[test]
+    fn plain_text_has_no_context() {
+        let document = Document::new_markdown_curated("Just a plain sentence.");
+
+        assert!(document
+            .tokens()
+            .all(|token| token.context == Default::default()));
+    }
 }
@@ -11,7 +11,7 @@ pub use markdown::Markdown;
 pub use mask::Mask;
 pub use plain_english::PlainEnglish;
 
-pub use crate::token::{Token, TokenKind, TokenStringExt};
+pub use crate::token::{Token, TokenContext, TokenKind, TokenStringExt};
 
 #[cfg(not(feature = "concurrent"))]
 #[blanket(derive(Box))]
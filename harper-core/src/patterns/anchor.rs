@@ -0,0 +1,203 @@
+use super::{Captures, MatchContext, Pattern};
+use crate::Token;
+
+/// A unit of text a pattern can be anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    Chunk,
+    Sentence,
+    Paragraph,
+}
+
+impl Boundary {
+    fn is_terminator(&self, token: &Token) -> bool {
+        match self {
+            Self::Chunk => token.is_chunk_terminator(),
+            Self::Sentence => token.is_sentence_terminator(),
+            Self::Paragraph => token.kind.is_paragraph_break(),
+        }
+    }
+}
+
+/// A pattern that matches another only at the start of a chunk, sentence or
+/// paragraph. Whitespace is ignored.
+pub struct StartOf {
+    boundary: Boundary,
+    inner: Box<dyn Pattern>,
+}
+
+impl StartOf {
+    pub fn new(boundary: Boundary, inner: Box<dyn Pattern>) -> Self {
+        Self { boundary, inner }
+    }
+
+    pub fn sentence(inner: Box<dyn Pattern>) -> Self {
+        Self::new(Boundary::Sentence, inner)
+    }
+
+    pub fn paragraph(inner: Box<dyn Pattern>) -> Self {
+        Self::new(Boundary::Paragraph, inner)
+    }
+}
+
+impl Pattern for StartOf {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let previous = context
+            .preceding()
+            .iter()
+            .rev()
+            .find(|t| !t.kind.is_whitespace());
+
+        if previous.is_some_and(|t| !self.boundary.is_terminator(t)) {
+            return 0;
+        }
+
+        self.inner.matches_in_context(context, source, captures)
+    }
+}
+
+/// A pattern that matches another only at the end of a chunk, sentence or
+/// paragraph. Whitespace is ignored.
+///
+/// The terminating punctuation (if any) is not included in the match.
+pub struct EndOf {
+    boundary: Boundary,
+    inner: Box<dyn Pattern>,
+}
+
+impl EndOf {
+    pub fn new(boundary: Boundary, inner: Box<dyn Pattern>) -> Self {
+        Self { boundary, inner }
+    }
+
+    pub fn sentence(inner: Box<dyn Pattern>) -> Self {
+        Self::new(Boundary::Sentence, inner)
+    }
+
+    pub fn paragraph(inner: Box<dyn Pattern>) -> Self {
+        Self::new(Boundary::Paragraph, inner)
+    }
+}
+
+impl Pattern for EndOf {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let mut inner = Captures::default();
+        let match_len = self.inner.matches_in_context(context, source, &mut inner);
+
+        if match_len == 0 {
+            return 0;
+        }
+
+        let next = context
+            .following(match_len)
+            .iter()
+            .find(|t| !t.kind.is_whitespace());
+
+        // A token that ends the sentence itself, like the "U.S." in "I live
+        // in the U.S. It is big.", ends it after that token, not before.
+        let ends_inside = self.boundary != Boundary::Paragraph
+            && context.following(match_len - 1)[0].context.sentence_end;
+        let ends_after =
+            next.is_none_or(|t| !t.context.sentence_end && self.boundary.is_terminator(t));
+
+        if !ends_inside && !ends_after {
+            return 0;
+        }
+
+        captures.append_offset(&mut inner, 0);
+        match_len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EndOf, StartOf};
+    use crate::patterns::{Boundary, MatchContext, Pattern, PatternExt, SequencePattern};
+    use crate::{Document, Span};
+
+    fn any_word() -> Box<dyn Pattern> {
+        Box::new(SequencePattern::default().then_any_word())
+    }
+
+    #[test]
+    fn finds_sentence_starts() {
+        let doc = Document::new_plain_english_curated("This is one. That is two.");
+        let pat = StartOf::sentence(any_word());
+
+        assert_eq!(
+            pat.find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(0, 1), Span::new(7, 8)]
+        );
+    }
+
+    #[test]
+    fn finds_sentence_ends() {
+        let doc = Document::new_plain_english_curated("This is one. That is two.");
+        let pat = EndOf::sentence(any_word());
+
+        assert_eq!(
+            pat.find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(4, 5), Span::new(11, 12)]
+        );
+    }
+
+    #[test]
+    fn finds_boundaries_at_initialisms_that_end_sentences() {
+        let doc = Document::new_plain_english_curated("I live in the U.S. It is big.");
+
+        assert_eq!(
+            StartOf::sentence(any_word()).find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(0, 1), Span::new(10, 11)]
+        );
+        assert_eq!(
+            EndOf::sentence(any_word()).find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(8, 9), Span::new(14, 15)]
+        );
+    }
+
+    #[test]
+    fn finds_chunk_starts() {
+        let doc = Document::new_plain_english_curated("Here, there");
+        let pat = StartOf::new(Boundary::Chunk, any_word());
+
+        assert_eq!(
+            pat.find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(0, 1), Span::new(3, 4)]
+        );
+    }
+
+    #[test]
+    fn needs_context_to_rule_out() {
+        let doc = Document::new_plain_english_curated("one two");
+        let pat = StartOf::sentence(any_word());
+
+        // Without context, nothing precedes "two".
+        assert_eq!(pat.matches(&doc.get_tokens()[2..], doc.get_source()), 1);
+        assert_eq!(
+            pat.matches_in_context(
+                MatchContext::new(doc.get_tokens()).advanced_by(2),
+                doc.get_source(),
+                &mut Default::default()
+            ),
+            0
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use super::{MatchContext, Pattern};
+use crate::{Span, Token};
+
+/// Named sub-ranges of a pattern match, recorded by [`Capture`] patterns.
+///
+/// The spans are measured in tokens, relative to the start of the match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Captures {
+    groups: Vec<(String, Span)>,
+}
+
+impl Captures {
+    pub fn insert(&mut self, name: impl Into<String>, span: Span) {
+        self.groups.push((name.into(), span));
+    }
+
+    /// Get the span of the first capture with the given name.
+    pub fn get(&self, name: &str) -> Option<Span> {
+        self.groups
+            .iter()
+            .find(|(group, _)| group == name)
+            .map(|(_, span)| *span)
+    }
+
+    /// Get the tokens captured under the given name from the tokens of the
+    /// match.
+    pub fn get_tokens<'a>(&self, name: &str, matched_tokens: &'a [Token]) -> Option<&'a [Token]> {
+        let span = self.get(name)?;
+        matched_tokens.get(span.start..span.end)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Span)> + '_ {
+        self.groups
+            .iter()
+            .map(|(group, span)| (group.as_str(), *span))
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.groups.clear();
+    }
+
+    /// Add captures that were recorded relative to a point `offset` tokens
+    /// into the match.
+    pub fn append_offset(&mut self, other: &mut Self, offset: usize) {
+        self.groups.extend(
+            other
+                .groups
+                .drain(..)
+                .map(|(group, span)| (group, span.pushed_by(offset))),
+        );
+    }
+}
+
+/// A pattern that records the tokens matched by another pattern under a name.
+pub struct Capture {
+    name: String,
+    inner: Box<dyn Pattern>,
+}
+
+impl Capture {
+    pub fn new(name: impl Into<String>, inner: Box<dyn Pattern>) -> Self {
+        Self {
+            name: name.into(),
+            inner,
+        }
+    }
+}
+
+impl Pattern for Capture {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.inner.matches(tokens, source)
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let len = self.inner.matches_in_context(context, source, captures);
+
+        if len > 0 {
+            captures.insert(self.name.clone(), Span::new_with_len(0, len));
+        }
+
+        len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Capture, Captures};
+    use crate::patterns::{
+        EitherPattern, MatchContext, Pattern, RepeatingPattern, SequencePattern,
+    };
+    use crate::{Document, Span};
+
+    #[test]
+    fn captures_inside_sequence() {
+        let pat = SequencePattern::default()
+            .then_exact_word("could")
+            .then_whitespace()
+            .then_capture(
+                "error",
+                Box::new(SequencePattern::default().then_any_word()),
+            );
+        let doc = Document::new_plain_english_curated("could of");
+
+        let mut captures = Captures::default();
+        let len = pat.matches_in_context(
+            MatchContext::new(doc.get_tokens()),
+            doc.get_source(),
+            &mut captures,
+        );
+
+        assert_eq!(len, 3);
+        assert_eq!(captures.get("error"), Some(Span::new(2, 3)));
+        assert_eq!(
+            captures
+                .get_tokens("error", doc.get_tokens())
+                .map(|t| t.len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn discards_captures_of_failed_alternatives() {
+        let pat = EitherPattern::new(vec![
+            Box::new(
+                SequencePattern::default()
+                    .then_capture(
+                        "first",
+                        Box::new(SequencePattern::default().then_any_word()),
+                    )
+                    .then_exact_word("never"),
+            ),
+            Box::new(Capture::new(
+                "second",
+                Box::new(SequencePattern::default().then_any_word()),
+            )),
+        ]);
+        let doc = Document::new_plain_english_curated("word");
+
+        let mut captures = Captures::default();
+        pat.matches_in_context(
+            MatchContext::new(doc.get_tokens()),
+            doc.get_source(),
+            &mut captures,
+        );
+
+        assert_eq!(captures.get("first"), None);
+        assert_eq!(captures.get("second"), Some(Span::new(0, 1)));
+    }
+
+    #[test]
+    fn offsets_repeated_captures() {
+        let pat = RepeatingPattern::new(Box::new(
+            SequencePattern::default()
+                .then_capture("word", Box::new(SequencePattern::default().then_any_word()))
+                .then_whitespace(),
+        ));
+        let doc = Document::new_plain_english_curated("a b c ");
+
+        let mut captures = Captures::default();
+        pat.matches_in_context(
+            MatchContext::new(doc.get_tokens()),
+            doc.get_source(),
+            &mut captures,
+        );
+
+        let spans: Vec<Span> = captures.iter().map(|(_, span)| span).collect();
+        assert_eq!(
+            spans,
+            vec![Span::new(0, 1), Span::new(2, 3), Span::new(4, 5)]
+        );
+    }
+}
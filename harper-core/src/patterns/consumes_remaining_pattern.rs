@@ -1,4 +1,4 @@
-use super::Pattern;
+use super::{Captures, MatchContext, Pattern};
 use crate::Token;
 
 /// A pattern that wraps another pattern.
@@ -16,9 +16,20 @@ impl ConsumesRemainingPattern {
 
 impl Pattern for ConsumesRemainingPattern {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
-        let match_len = self.inner.matches(tokens, source);
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let mut inner = Captures::default();
+        let match_len = self.inner.matches_in_context(context, source, &mut inner);
 
-        if match_len == tokens.len() {
+        if match_len == context.window().len() {
+            captures.append_offset(&mut inner, 0);
             match_len
         } else {
             0
@@ -0,0 +1,712 @@
+//! A small textual language for writing [`Pattern`]s without touching Rust.
+//!
+//! A pattern is a sequence of elements, each matching one or more tokens:
+//!
+//! | Syntax               | Matches                                                      |
+//! | -------------------- | ------------------------------------------------------------ |
+//! | `"could of"`         | The literal text, ignoring case.                             |
+//! | `word("a"\|"b")`     | Any one of the listed words, ignoring case.                  |
+//! | `NOUN`               | Any token with the given tag (see below).                    |
+//! | `[ADJ\|ADV]`         | Any token with at least one of the given tags.               |
+//! | `_`                  | Whitespace.                                                  |
+//! | `!X`                 | Any single token that `X` does not match.                    |
+//! | `(X Y \| Z)`         | Either the sequence `X Y` or `Z`.                            |
+//! | `X+`, `X*`, `X?`     | One or more, zero or more, or zero or one `X`.               |
+//! | `name:X`             | `X`, recording the tokens it matched as a [capture](super::Captures). |
+//!
+//! Whitespace between elements is optional unless `_` is used to require it,
+//! so `[ADJ]+ NOUN` matches "big red car".
+//! Repetition is greedy and never backtracks.
+//! Quantifiers apply to the whole capture, so use `name:(X+)` to capture a
+//! run of tokens.
+//!
+//! The available tags are `ANY`, `WORD`, `NOUN`, `PROPN` (proper nouns),
+//! `PLURAL`, `POSS` (possessive nouns), `PRON`, `VERB`, `LINKING` (linking
+//! verbs), `ADJ`, `ADV`, `CONJ`, `ART` (articles), `PREP` (prepositions),
+//! `SWEAR`, `COMMON`, `HOMOGRAPH`, `PUNCT`, `COMMA`, `PERIOD` and `NUM`.
+
+use super::{
+    Capture, EitherPattern, Invert, Pattern, RepeatingPattern, SequencePattern, WordPatternGroup,
+};
+use super::{NaivePatternGroup, WhitespacePattern};
+use crate::{CharStringExt, Document, Token, TokenKind, WordMetadata};
+
+/// The maximum number of optional elements (`?` or `*`) in a single sequence.
+/// Each one doubles the number of alternatives the sequence is compiled into.
+const MAX_OPTIONAL: usize = 6;
+
+type Predicate = fn(&TokenKind) -> bool;
+
+const TAGS: &[(&str, Predicate)] = &[
+    ("ANY", |_| true),
+    ("WORD", TokenKind::is_word),
+    ("NOUN", |k| k.as_word().is_some_and(WordMetadata::is_noun)),
+    ("PROPN", |k| {
+        k.as_word().is_some_and(WordMetadata::is_proper_noun)
+    }),
+    ("PLURAL", |k| {
+        k.as_word().is_some_and(WordMetadata::is_plural_noun)
+    }),
+    ("POSS", |k| {
+        k.as_word().is_some_and(WordMetadata::is_possessive_noun)
+    }),
+    ("PRON", |k| {
+        k.as_word().is_some_and(WordMetadata::is_pronoun_noun)
+    }),
+    ("VERB", |k| k.as_word().is_some_and(WordMetadata::is_verb)),
+    ("LINKING", |k| {
+        k.as_word().is_some_and(WordMetadata::is_linking_verb)
+    }),
+    ("ADJ", |k| {
+        k.as_word().is_some_and(WordMetadata::is_adjective)
+    }),
+    ("ADV", |k| k.as_word().is_some_and(WordMetadata::is_adverb)),
+    ("CONJ", |k| {
+        k.as_word().is_some_and(WordMetadata::is_conjunction)
+    }),
+    ("ART", |k| k.as_word().is_some_and(|m| m.article)),
+    ("PREP", |k| k.as_word().is_some_and(|m| m.preposition)),
+    ("SWEAR", |k| k.as_word().is_some_and(WordMetadata::is_swear)),
+    ("COMMON", |k| k.as_word().is_some_and(|m| m.common)),
+    ("HOMOGRAPH", |k| {
+        k.as_word().is_some_and(WordMetadata::is_likely_homograph)
+    }),
+    ("PUNCT", TokenKind::is_punctuation),
+    ("COMMA", TokenKind::is_comma),
+    ("PERIOD", TokenKind::is_period),
+    ("NUM", TokenKind::is_number),
+];
+
+/// An error encountered while parsing a pattern.
+/// Indices are measured in characters from the start of the pattern.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DslError {
+    #[error("Unexpected character `{ch}` at index {index}.")]
+    UnexpectedChar { ch: char, index: usize },
+    #[error("Unterminated string starting at index {0}.")]
+    UnterminatedString(usize),
+    #[error("Unknown tag `{tag}` at index {index}.")]
+    UnknownTag { tag: String, index: usize },
+    #[error("Expected {expected} at index {index}.")]
+    Expected {
+        expected: &'static str,
+        index: usize,
+    },
+    #[error("A sequence may contain at most {MAX_OPTIONAL} optional elements.")]
+    TooManyOptional,
+}
+
+/// Parse a pattern written in the pattern language described in the
+/// [module documentation](self).
+pub fn parse_pattern(source: &str) -> Result<Box<dyn Pattern>, DslError> {
+    let tokens = lex(source)?;
+    let mut parser = Parser {
+        tokens,
+        cursor: 0,
+        end: source.chars().count(),
+    };
+
+    let alternatives = parser.parse_alternatives()?;
+
+    if let Some((index, _)) = parser.peek() {
+        return Err(DslError::Expected {
+            expected: "the end of the pattern",
+            index,
+        });
+    }
+
+    compile_alternatives(&alternatives)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DslToken {
+    Str(String),
+    Ident(String),
+    Underscore,
+    Pipe,
+    OpenParen,
+    CloseParen,
+    OpenSquare,
+    CloseSquare,
+    Plus,
+    Star,
+    Question,
+    Bang,
+    Colon,
+}
+
+fn lex(source: &str) -> Result<Vec<(usize, DslToken)>, DslError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut cursor = 0;
+
+    while let Some(&c) = chars.get(cursor) {
+        let start = cursor;
+        cursor += 1;
+
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '"' => {
+                let len = chars[cursor..]
+                    .iter()
+                    .position(|c| *c == '"')
+                    .ok_or(DslError::UnterminatedString(start))?;
+
+                let text = chars[cursor..cursor + len].iter().collect();
+                cursor += len + 1;
+                DslToken::Str(text)
+            }
+            c if c.is_ascii_alphabetic() => {
+                let len = chars[cursor..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_alphabetic())
+                    .count();
+
+                let ident = chars[start..cursor + len].iter().collect();
+                cursor += len;
+                DslToken::Ident(ident)
+            }
+            '_' => DslToken::Underscore,
+            '|' => DslToken::Pipe,
+            '(' => DslToken::OpenParen,
+            ')' => DslToken::CloseParen,
+            '[' => DslToken::OpenSquare,
+            ']' => DslToken::CloseSquare,
+            '+' => DslToken::Plus,
+            '*' => DslToken::Star,
+            '?' => DslToken::Question,
+            '!' => DslToken::Bang,
+            ':' => DslToken::Colon,
+            ch => return Err(DslError::UnexpectedChar { ch, index: start }),
+        };
+
+        tokens.push((start, token));
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    /// A single token with the given (lowercase) content.
+    Exact(Vec<char>),
+    /// A single word from a set.
+    WordSet(Vec<String>),
+    /// A single token that satisfies any of the predicates.
+    Tags(Vec<Predicate>),
+    Whitespace,
+    Not(Box<Node>),
+    Group(Vec<Sequence>),
+    Capture(String, Box<Node>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantifier {
+    One,
+    OneOrMore,
+    ZeroOrMore,
+    ZeroOrOne,
+}
+
+impl Quantifier {
+    fn is_optional(self) -> bool {
+        matches!(self, Self::ZeroOrMore | Self::ZeroOrOne)
+    }
+
+    fn repeats(self) -> bool {
+        matches!(self, Self::OneOrMore | Self::ZeroOrMore)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    node: Node,
+    quantifier: Quantifier,
+}
+
+type Sequence = Vec<Item>;
+
+struct Parser {
+    tokens: Vec<(usize, DslToken)>,
+    cursor: usize,
+    /// The index reported for errors at the end of the pattern.
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<(usize, &DslToken)> {
+        self.tokens.get(self.cursor).map(|(i, t)| (*i, t))
+    }
+
+    fn next_index(&self) -> usize {
+        self.peek().map(|(i, _)| i).unwrap_or(self.end)
+    }
+
+    fn eat(&mut self, token: &DslToken) -> bool {
+        if self.peek().is_some_and(|(_, t)| t == token) {
+            self.cursor += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &DslToken, expected: &'static str) -> Result<(), DslError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(DslError::Expected {
+                expected,
+                index: self.next_index(),
+            })
+        }
+    }
+
+    fn parse_alternatives(&mut self) -> Result<Vec<Sequence>, DslError> {
+        let mut alternatives = vec![self.parse_sequence()?];
+
+        while self.eat(&DslToken::Pipe) {
+            alternatives.push(self.parse_sequence()?);
+        }
+
+        Ok(alternatives)
+    }
+
+    fn parse_sequence(&mut self) -> Result<Sequence, DslError> {
+        let mut items = Vec::new();
+
+        while let Some((_, token)) = self.peek() {
+            if matches!(token, DslToken::Pipe | DslToken::CloseParen) {
+                break;
+            }
+
+            let node = self.parse_node()?;
+
+            let quantifier = if self.eat(&DslToken::Plus) {
+                Quantifier::OneOrMore
+            } else if self.eat(&DslToken::Star) {
+                Quantifier::ZeroOrMore
+            } else if self.eat(&DslToken::Question) {
+                Quantifier::ZeroOrOne
+            } else {
+                Quantifier::One
+            };
+
+            items.push(Item { node, quantifier });
+        }
+
+        if items.is_empty() {
+            return Err(DslError::Expected {
+                expected: "a pattern element",
+                index: self.next_index(),
+            });
+        }
+
+        Ok(items)
+    }
+
+    fn parse_node(&mut self) -> Result<Node, DslError> {
+        let index = self.next_index();
+
+        let Some((_, token)) = self.peek() else {
+            return Err(DslError::Expected {
+                expected: "a pattern element",
+                index,
+            });
+        };
+
+        let token = token.clone();
+        self.cursor += 1;
+
+        match token {
+            DslToken::Str(text) => parse_literal(&text, index),
+            DslToken::Ident(name) if self.eat(&DslToken::Colon) => {
+                Ok(Node::Capture(name, Box::new(self.parse_node()?)))
+            }
+            DslToken::Ident(ident) if ident == "word" => self.parse_word_set(),
+            DslToken::Ident(ident) => Ok(Node::Tags(vec![lookup_tag(&ident, index)?])),
+            DslToken::Underscore => Ok(Node::Whitespace),
+            DslToken::Bang => Ok(Node::Not(Box::new(self.parse_node()?))),
+            DslToken::OpenParen => {
+                let alternatives = self.parse_alternatives()?;
+                self.expect(&DslToken::CloseParen, "`)`")?;
+                Ok(Node::Group(alternatives))
+            }
+            DslToken::OpenSquare => {
+                let mut predicates = Vec::new();
+
+                loop {
+                    let index = self.next_index();
+
+                    match self.peek() {
+                        Some((_, DslToken::Ident(ident))) => {
+                            predicates.push(lookup_tag(&ident.clone(), index)?);
+                            self.cursor += 1;
+                        }
+                        _ => {
+                            return Err(DslError::Expected {
+                                expected: "a tag",
+                                index,
+                            })
+                        }
+                    }
+
+                    if !self.eat(&DslToken::Pipe) {
+                        break;
+                    }
+                }
+
+                self.expect(&DslToken::CloseSquare, "`]`")?;
+                Ok(Node::Tags(predicates))
+            }
+            _ => Err(DslError::Expected {
+                expected: "a pattern element",
+                index,
+            }),
+        }
+    }
+
+    fn parse_word_set(&mut self) -> Result<Node, DslError> {
+        self.expect(&DslToken::OpenParen, "`(`")?;
+
+        let mut words = Vec::new();
+
+        loop {
+            let index = self.next_index();
+
+            match self.peek() {
+                Some((_, DslToken::Str(word)))
+                    if !word.is_empty() && !word.contains(char::is_whitespace) =>
+                {
+                    words.push(word.to_lowercase());
+                    self.cursor += 1;
+                }
+                _ => {
+                    return Err(DslError::Expected {
+                        expected: "a quoted word",
+                        index,
+                    })
+                }
+            }
+
+            if !self.eat(&DslToken::Pipe) {
+                break;
+            }
+        }
+
+        self.expect(&DslToken::CloseParen, "`)`")?;
+        Ok(Node::WordSet(words))
+    }
+}
+
+fn lookup_tag(tag: &str, index: usize) -> Result<Predicate, DslError> {
+    TAGS.iter()
+        .find(|(name, _)| *name == tag)
+        .map(|(_, predicate)| *predicate)
+        .ok_or_else(|| DslError::UnknownTag {
+            tag: tag.to_string(),
+            index,
+        })
+}
+
+/// Split a quoted literal into tokens the same way a document would be.
+fn parse_literal(text: &str, index: usize) -> Result<Node, DslError> {
+    let document = Document::new_untagged(text.trim());
+
+    let items: Sequence = document
+        .tokens()
+        .map(|token| Item {
+            node: if token.kind.is_whitespace() {
+                Node::Whitespace
+            } else {
+                Node::Exact(document.get_span_content(token.span).to_lower().to_vec())
+            },
+            quantifier: Quantifier::One,
+        })
+        .collect();
+
+    match items.len() {
+        0 => Err(DslError::Expected {
+            expected: "a non-empty string",
+            index,
+        }),
+        1 => Ok(items.into_iter().next().unwrap().node),
+        _ => Ok(Node::Group(vec![items])),
+    }
+}
+
+fn compile_alternatives(alternatives: &[Sequence]) -> Result<Box<dyn Pattern>, DslError> {
+    let mut patterns = Vec::new();
+
+    for sequence in alternatives {
+        for expansion in expand_optional(sequence)? {
+            if !expansion.is_empty() {
+                patterns.push(compile_sequence(&expansion)?);
+            }
+        }
+    }
+
+    if patterns.len() == 1 {
+        Ok(patterns.pop().unwrap())
+    } else {
+        Ok(Box::new(EitherPattern::new(patterns)))
+    }
+}
+
+/// Since a [`Pattern`] cannot successfully match zero tokens, each optional
+/// element is removed by producing one version of the sequence with it and one
+/// without.
+///
+/// Versions that include more of the optional elements come first, so they are
+/// preferred.
+fn expand_optional(sequence: &[Item]) -> Result<Vec<Vec<(&Node, bool)>>, DslError> {
+    let optional_count = sequence
+        .iter()
+        .filter(|item| item.quantifier.is_optional())
+        .count();
+
+    if optional_count > MAX_OPTIONAL {
+        return Err(DslError::TooManyOptional);
+    }
+
+    let mut expansions = Vec::new();
+
+    for mask in (0..1usize << optional_count).rev() {
+        let mut optional_index = optional_count;
+        let mut expansion = Vec::new();
+
+        for item in sequence {
+            if item.quantifier.is_optional() {
+                optional_index -= 1;
+
+                if mask & (1 << optional_index) == 0 {
+                    continue;
+                }
+            }
+
+            expansion.push((&item.node, item.quantifier.repeats()));
+        }
+
+        expansions.push(expansion);
+    }
+
+    Ok(expansions)
+}
+
+fn compile_sequence(elements: &[(&Node, bool)]) -> Result<Box<dyn Pattern>, DslError> {
+    let mut pattern = SequencePattern::default();
+
+    for (index, (node, repeats)) in elements.iter().enumerate() {
+        let joined = index > 0
+            && !matches!(node, Node::Whitespace)
+            && !matches!(elements[index - 1].0, Node::Whitespace);
+
+        let element = if joined {
+            optionally_spaced(|| compile_repeated(node, *repeats))?
+        } else {
+            compile_repeated(node, *repeats)?
+        };
+
+        pattern = pattern.then(element);
+    }
+
+    Ok(Box::new(pattern))
+}
+
+/// Match the produced pattern, optionally preceded by whitespace.
+fn optionally_spaced(
+    mut compile: impl FnMut() -> Result<Box<dyn Pattern>, DslError>,
+) -> Result<Box<dyn Pattern>, DslError> {
+    Ok(Box::new(EitherPattern::new(vec![
+        Box::new(
+            SequencePattern::default()
+                .then_whitespace()
+                .then(compile()?),
+        ),
+        compile()?,
+    ])))
+}
+
+fn compile_repeated(node: &Node, repeats: bool) -> Result<Box<dyn Pattern>, DslError> {
+    if !repeats {
+        return compile_node(node);
+    }
+
+    let repetition = RepeatingPattern::new(optionally_spaced(|| compile_node(node))?);
+
+    Ok(Box::new(EitherPattern::new(vec![
+        Box::new(
+            SequencePattern::default()
+                .then(compile_node(node)?)
+                .then(Box::new(repetition)),
+        ),
+        compile_node(node)?,
+    ])))
+}
+
+fn compile_node(node: &Node) -> Result<Box<dyn Pattern>, DslError> {
+    Ok(match node {
+        Node::Exact(content) => {
+            let content = content.clone();
+
+            Box::new(move |tok: &Token, source: &[char]| {
+                tok.span.get_content(source).to_lower().as_ref() == content.as_slice()
+            })
+        }
+        Node::WordSet(words) => {
+            let mut group = WordPatternGroup::<NaivePatternGroup>::default();
+
+            for word in words {
+                let mut variants = vec![word.clone(), capitalize(word), word.to_uppercase()];
+                variants.dedup();
+
+                for variant in variants {
+                    group.add_word(&variant);
+                }
+            }
+
+            Box::new(group)
+        }
+        Node::Tags(predicates) => {
+            let predicates = predicates.clone();
+
+            Box::new(move |tok: &Token, _source: &[char]| {
+                predicates.iter().any(|predicate| predicate(&tok.kind))
+            })
+        }
+        Node::Whitespace => Box::new(WhitespacePattern),
+        Node::Not(inner) => Box::new(Invert::new(compile_node(inner)?)),
+        Node::Group(alternatives) => compile_alternatives(alternatives)?,
+        Node::Capture(name, inner) => Box::new(Capture::new(name.as_str(), compile_node(inner)?)),
+    })
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_pattern, DslError};
+    use crate::patterns::{Captures, MatchContext};
+    use crate::{Document, Span};
+
+    /// The number of tokens matched at the start of the text.
+    fn match_len(pattern: &str, text: &str) -> usize {
+        let pattern = parse_pattern(pattern).unwrap();
+        let document = Document::new_plain_english_curated(text);
+
+        pattern.matches(document.get_tokens(), document.get_source())
+    }
+
+    fn matches_all(pattern: &str, text: &str) -> bool {
+        let document = Document::new_plain_english_curated(text);
+        match_len(pattern, text) == document.get_tokens().len()
+    }
+
+    #[test]
+    fn matches_literal_words() {
+        assert!(matches_all(r#""could" _ "of""#, "could of"));
+        assert!(matches_all(r#""could" _ "of""#, "Could Of"));
+        assert_eq!(match_len(r#""could" _ "of""#, "could have"), 0);
+    }
+
+    #[test]
+    fn matches_multi_word_literal() {
+        assert!(matches_all(r#""could of""#, "could of"));
+        assert!(matches_all(r#""don't""#, "Don't"));
+    }
+
+    #[test]
+    fn matches_repeated_tags() {
+        assert!(matches_all("[ADJ]+ NOUN", "big red car"));
+        assert!(matches_all("[ADJ]+ NOUN", "red car"));
+        assert_eq!(match_len("[ADJ]+ NOUN", "the car"), 0);
+    }
+
+    #[test]
+    fn matches_articles_and_prepositions() {
+        assert!(matches_all("PREP _ ART _ NOUN", "under the bridge"));
+        assert!(matches_all("ART _ NOUN", "an apple"));
+        assert_eq!(match_len("PREP", "bridge"), 0);
+        assert_eq!(match_len("ART", "apple"), 0);
+    }
+
+    #[test]
+    fn matches_word_sets() {
+        let pattern = r#"word("their"|"there") _ VERB"#;
+
+        assert!(matches_all(pattern, "there is"));
+        assert!(matches_all(pattern, "Their run"));
+        assert_eq!(match_len(pattern, "they is"), 0);
+    }
+
+    #[test]
+    fn matches_groups_and_alternatives() {
+        let pattern = r#""a" _ ("lot" | "bunch" _ "of")"#;
+
+        assert!(matches_all(pattern, "a lot"));
+        assert!(matches_all(pattern, "a bunch of"));
+        assert_eq!(match_len(pattern, "a few"), 0);
+    }
+
+    #[test]
+    fn matches_optional_elements() {
+        let pattern = r#""very"? "good""#;
+
+        assert!(matches_all(pattern, "very good"));
+        assert!(matches_all(pattern, "good"));
+    }
+
+    #[test]
+    fn inverts_elements() {
+        assert!(matches_all(r#""the" _ !VERB"#, "the car"));
+        assert_eq!(match_len(r#""the" _ !VERB"#, "the cat"), 0);
+    }
+
+    #[test]
+    fn records_captures() {
+        let pattern = parse_pattern(r#""could" _ error:"of" _ VERB"#).unwrap();
+        let document = Document::new_plain_english_curated("could of been");
+
+        let mut captures = Captures::default();
+        pattern.matches_in_context(
+            MatchContext::new(document.get_tokens()),
+            document.get_source(),
+            &mut captures,
+        );
+
+        assert_eq!(captures.get("error"), Some(Span::new(2, 3)));
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert!(matches!(
+            parse_pattern("NOPE"),
+            Err(DslError::UnknownTag { index: 0, .. })
+        ));
+        assert_eq!(
+            parse_pattern(r#""could"#).err(),
+            Some(DslError::UnterminatedString(0))
+        );
+        assert_eq!(
+            parse_pattern("(NOUN").err(),
+            Some(DslError::Expected {
+                expected: "`)`",
+                index: 5
+            })
+        );
+        assert_eq!(
+            parse_pattern("NOUN |").err(),
+            Some(DslError::Expected {
+                expected: "a pattern element",
+                index: 6
+            })
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::Token;
 
-use super::Pattern;
+use super::{Captures, MatchContext, Pattern};
 
 /// A pattern that returns the value of the first non-zero match in a list.
 #[derive(Default)]
@@ -16,10 +16,21 @@ impl EitherPattern {
 
 impl Pattern for EitherPattern {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
         for pattern in self.patterns.iter() {
-            let match_len = pattern.matches(tokens, source);
+            let mut inner = Captures::default();
+            let match_len = pattern.matches_in_context(context, source, &mut inner);
 
             if match_len > 0 {
+                captures.append_offset(&mut inner, 0);
                 return match_len;
             }
         }
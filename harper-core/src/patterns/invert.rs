@@ -1,6 +1,6 @@
 use crate::Token;
 
-use super::Pattern;
+use super::{Captures, MatchContext, Pattern};
 
 /// A struct that matches any pattern __except__ the one provided.
 pub struct Invert {
@@ -15,7 +15,24 @@ impl Invert {
 
 impl Pattern for Invert {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
-        if self.inner.matches(tokens, source) != 0 {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        _captures: &mut Captures,
+    ) -> usize {
+        if context.window().is_empty() {
+            return 0;
+        }
+
+        if self
+            .inner
+            .matches_in_context(context, source, &mut Captures::default())
+            != 0
+        {
             0
         } else {
             1
@@ -0,0 +1,199 @@
+use super::{Captures, MatchContext, Pattern};
+use crate::Token;
+
+/// The maximum number of tokens a [`Lookbehind`] will inspect.
+const MAX_LOOKBEHIND: usize = 32;
+
+/// A pattern that matches another only if it is (or is not) directly preceded
+/// by a third.
+///
+/// The preceding tokens are not included in the match, and may span at most
+/// 32 tokens.
+pub struct Lookbehind {
+    behind: Box<dyn Pattern>,
+    inner: Box<dyn Pattern>,
+    negate: bool,
+}
+
+impl Lookbehind {
+    /// Match `inner` only when directly preceded by `behind`.
+    pub fn new(behind: Box<dyn Pattern>, inner: Box<dyn Pattern>) -> Self {
+        Self {
+            behind,
+            inner,
+            negate: false,
+        }
+    }
+
+    /// Match `inner` only when __not__ directly preceded by `behind`.
+    pub fn negative(behind: Box<dyn Pattern>, inner: Box<dyn Pattern>) -> Self {
+        Self {
+            behind,
+            inner,
+            negate: true,
+        }
+    }
+
+    fn is_preceded(&self, context: MatchContext, source: &[char]) -> bool {
+        let lowest = context.index.saturating_sub(MAX_LOOKBEHIND);
+
+        (lowest..context.index).rev().any(|start| {
+            let window = MatchContext {
+                tokens: context.tokens,
+                index: start,
+                end: context.index,
+            };
+
+            self.behind
+                .matches_in_context(window, source, &mut Captures::default())
+                == context.index - start
+        })
+    }
+}
+
+impl Pattern for Lookbehind {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        if self.is_preceded(context, source) == self.negate {
+            return 0;
+        }
+
+        self.inner.matches_in_context(context, source, captures)
+    }
+}
+
+/// A pattern that matches another only if it is (or is not) directly followed
+/// by a third.
+///
+/// The following tokens are not included in the match, and may extend past
+/// the end of the current chunk.
+pub struct Lookahead {
+    inner: Box<dyn Pattern>,
+    ahead: Box<dyn Pattern>,
+    negate: bool,
+}
+
+impl Lookahead {
+    /// Match `inner` only when directly followed by `ahead`.
+    pub fn new(inner: Box<dyn Pattern>, ahead: Box<dyn Pattern>) -> Self {
+        Self {
+            inner,
+            ahead,
+            negate: false,
+        }
+    }
+
+    /// Match `inner` only when __not__ directly followed by `ahead`.
+    pub fn negative(inner: Box<dyn Pattern>, ahead: Box<dyn Pattern>) -> Self {
+        Self {
+            inner,
+            ahead,
+            negate: true,
+        }
+    }
+}
+
+impl Pattern for Lookahead {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let mut inner = Captures::default();
+        let match_len = self.inner.matches_in_context(context, source, &mut inner);
+
+        if match_len == 0 {
+            return 0;
+        }
+
+        let after = MatchContext {
+            tokens: context.tokens,
+            index: context.index + match_len,
+            end: context.tokens.len(),
+        };
+
+        let is_followed = self
+            .ahead
+            .matches_in_context(after, source, &mut Captures::default())
+            != 0;
+
+        if is_followed == self.negate {
+            return 0;
+        }
+
+        captures.append_offset(&mut inner, 0);
+        match_len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lookahead, Lookbehind};
+    use crate::patterns::{Pattern, PatternExt, SequencePattern};
+    use crate::{Document, Span};
+
+    fn the() -> Box<dyn Pattern> {
+        Box::new(
+            SequencePattern::default()
+                .then_exact_word_or_lowercase("the")
+                .then_whitespace(),
+        )
+    }
+
+    fn word(word: &str) -> Box<dyn Pattern> {
+        Box::new(SequencePattern::default().then_exact_word(word))
+    }
+
+    #[test]
+    fn matches_when_preceded() {
+        let doc = Document::new_plain_english_curated("the cat and a cat");
+        let pat = Lookbehind::new(the(), word("cat"));
+
+        assert_eq!(
+            pat.find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(2, 3)]
+        );
+    }
+
+    #[test]
+    fn matches_when_not_preceded() {
+        let doc = Document::new_plain_english_curated("the cat and a cat");
+        let pat = Lookbehind::negative(the(), word("cat"));
+
+        assert_eq!(
+            pat.find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(8, 9)]
+        );
+    }
+
+    #[test]
+    fn matches_when_not_followed() {
+        let doc = Document::new_plain_english_curated("a cat sat, a cat ran");
+        let pat = Lookahead::negative(
+            word("cat"),
+            Box::new(
+                SequencePattern::default()
+                    .then_whitespace()
+                    .then_exact_word("sat"),
+            ),
+        );
+
+        assert_eq!(
+            pat.find_all_matches(doc.get_tokens(), doc.get_source()),
+            vec![Span::new(9, 10)]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Token;
+
+/// Where a pattern is being matched within a larger run of tokens (usually
+/// the whole document).
+///
+/// This lets patterns like [`Lookbehind`](super::Lookbehind) and
+/// [`StartOf`](super::StartOf) look at the tokens around the ones they match.
+#[derive(Debug, Clone, Copy)]
+pub struct MatchContext<'a> {
+    /// All the tokens available.
+    pub tokens: &'a [Token],
+    /// The index of the first token to be matched.
+    pub index: usize,
+    /// The (exclusive) index a match may not extend past, like the end of the
+    /// current chunk.
+    pub end: usize,
+}
+
+impl<'a> MatchContext<'a> {
+    /// A context in which nothing precedes or follows the tokens.
+    pub fn new(tokens: &'a [Token]) -> Self {
+        Self {
+            tokens,
+            index: 0,
+            end: tokens.len(),
+        }
+    }
+
+    /// The tokens a match may consume.
+    pub fn window(&self) -> &'a [Token] {
+        self.tokens.get(self.index..self.end).unwrap_or_default()
+    }
+
+    /// The tokens before the start of the window.
+    pub fn preceding(&self) -> &'a [Token] {
+        &self.tokens[..self.index.min(self.tokens.len())]
+    }
+
+    /// The tokens after a match of the given length, including those past
+    /// the end of the window.
+    pub fn following(&self, match_len: usize) -> &'a [Token] {
+        self.tokens
+            .get(self.index + match_len..)
+            .unwrap_or_default()
+    }
+
+    /// The same context, moved forward by a number of tokens.
+    pub fn advanced_by(&self, by: usize) -> Self {
+        Self {
+            index: self.index + by,
+            ..*self
+        }
+    }
+}
@@ -2,10 +2,15 @@ use std::collections::VecDeque;
 
 use crate::{Span, Token, VecExt};
 
+mod anchor;
 mod any_pattern;
+mod capture;
 mod consumes_remaining_pattern;
+mod dsl;
 mod either_pattern;
 mod invert;
+mod lookaround;
+mod match_context;
 mod naive_pattern_group;
 mod repeating_pattern;
 mod sequence_pattern;
@@ -13,11 +18,16 @@ mod token_kind_pattern_group;
 mod whitespace_pattern;
 mod word_pattern_group;
 
+pub use anchor::{Boundary, EndOf, StartOf};
 pub use any_pattern::AnyPattern;
 use blanket::blanket;
+pub use capture::{Capture, Captures};
 pub use consumes_remaining_pattern::ConsumesRemainingPattern;
+pub use dsl::{parse_pattern, DslError};
 pub use either_pattern::EitherPattern;
 pub use invert::Invert;
+pub use lookaround::{Lookahead, Lookbehind};
+pub use match_context::MatchContext;
 pub use naive_pattern_group::NaivePatternGroup;
 pub use repeating_pattern::RepeatingPattern;
 pub use sequence_pattern::SequencePattern;
@@ -29,12 +39,40 @@ pub use word_pattern_group::WordPatternGroup;
 #[blanket(derive(Rc, Arc))]
 pub trait Pattern {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize;
+
+    /// Same as [`Self::matches`], but with knowledge of the surrounding tokens.
+    /// It also records the sub-ranges of the match named by any [`Capture`]
+    /// patterns within.
+    ///
+    /// Nothing is added to `captures` if the pattern does not match.
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        _captures: &mut Captures,
+    ) -> usize {
+        self.matches(context.window(), source)
+    }
 }
 
 #[cfg(feature = "concurrent")]
 #[blanket(derive(Arc))]
 pub trait Pattern: Send + Sync {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize;
+
+    /// Same as [`Self::matches`], but with knowledge of the surrounding tokens.
+    /// It also records the sub-ranges of the match named by any [`Capture`]
+    /// patterns within.
+    ///
+    /// Nothing is added to `captures` if the pattern does not match.
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        _captures: &mut Captures,
+    ) -> usize {
+        self.matches(context.window(), source)
+    }
 }
 
 pub trait PatternExt {
@@ -49,8 +87,11 @@ where
     fn find_all_matches(&self, tokens: &[Token], source: &[char]) -> Vec<Span> {
         let mut found = Vec::new();
 
+        let context = MatchContext::new(tokens);
+
         for i in 0..tokens.len() {
-            let len = self.matches(&tokens[i..], source);
+            let len =
+                self.matches_in_context(context.advanced_by(i), source, &mut Captures::default());
 
             if len > 0 {
                 found.push(Span::new_with_len(i, len));
@@ -1,4 +1,4 @@
-use super::Pattern;
+use super::{Captures, MatchContext, Pattern};
 use crate::Token;
 
 /// A naive pattern collection that naively iterates through a list of patterns,
@@ -16,17 +16,25 @@ impl NaivePatternGroup {
 
 impl Pattern for NaivePatternGroup {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
-        self.patterns
-            .iter()
-            .find_map(|p| {
-                let res = p.matches(tokens, source);
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        for pattern in self.patterns.iter() {
+            let mut inner = Captures::default();
+            let res = pattern.matches_in_context(context, source, &mut inner);
+
+            if res != 0 {
+                captures.append_offset(&mut inner, 0);
+                return res;
+            }
+        }
 
-                if res != 0 {
-                    Some(res)
-                } else {
-                    None
-                }
-            })
-            .unwrap_or_default()
+        0
     }
 }
@@ -1,4 +1,4 @@
-use super::Pattern;
+use super::{Captures, MatchContext, Pattern};
 use crate::Token;
 
 /// A pattern that will match one or more repetitions of the same pattern.
@@ -16,14 +16,27 @@ impl RepeatingPattern {
 
 impl Pattern for RepeatingPattern {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
         let mut tok_cursor = 0;
 
         loop {
-            let match_len = self.inner.matches(&tokens[tok_cursor..], source);
+            let mut inner = Captures::default();
+            let match_len =
+                self.inner
+                    .matches_in_context(context.advanced_by(tok_cursor), source, &mut inner);
 
             if match_len == 0 {
                 return tok_cursor;
             } else {
+                captures.append_offset(&mut inner, tok_cursor);
                 tok_cursor += match_len;
             }
         }
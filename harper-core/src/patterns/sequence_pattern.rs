@@ -2,8 +2,8 @@ use hashbrown::HashSet;
 use paste::paste;
 
 use super::whitespace_pattern::WhitespacePattern;
-use super::{Pattern, RepeatingPattern};
-use crate::{CharStringExt, Lrc, Token, TokenKind};
+use super::{Capture, Captures, MatchContext, Pattern, RepeatingPattern};
+use crate::{CharStringExt, Document, Lrc, Token, TokenKind};
 
 /// A pattern that checks that a sequence of others patterns match.
 #[derive(Default)]
@@ -44,48 +44,61 @@ impl SequencePattern {
     gen_then_from_is!(adverb);
     gen_then_from_is!(adjective);
 
-    pub fn then_exact_word(mut self, word: &'static str) -> Self {
-        self.token_patterns
-            .push(Box::new(|tok: &Token, source: &[char]| {
-                if !tok.kind.is_word() {
-                    return false;
-                }
-
-                let tok_chars = tok.span.get_content(source);
+    /// Match a phrase, like "in order to", ignoring the case of its words.
+    pub fn from_phrase(phrase: &str) -> Self {
+        Self::phrase_with(phrase, Self::then_exact_word_or_lowercase)
+    }
 
-                let mut w_char_count = 0;
-                for (i, w_char) in word.chars().enumerate() {
-                    w_char_count += 1;
+    /// Match a phrase, like "GitHub Actions", only where its words are cased
+    /// exactly the same.
+    pub fn from_exact_phrase(phrase: &str) -> Self {
+        Self::phrase_with(phrase, Self::then_exact_word)
+    }
 
-                    if tok_chars.get(i).cloned() != Some(w_char) {
-                        return false;
-                    }
-                }
+    fn phrase_with(phrase: &str, then_word: fn(Self, &str) -> Self) -> Self {
+        // Parsing the phrase as a document ensures it is tokenized the same way
+        // as the text it is matched against.
+        let document = Document::new_untagged(phrase.trim());
+
+        let mut pattern = Self::default();
+
+        for token in document.tokens() {
+            pattern = if token.kind.is_word() {
+                then_word(pattern, &document.get_span_content_str(token.span))
+            } else if token.kind.is_whitespace() {
+                pattern.then_whitespace()
+            } else {
+                pattern.then_loose(token.kind)
+            };
+        }
 
-                w_char_count == tok_chars.len()
-            }));
-        self
+        pattern
     }
 
-    pub fn then_exact_word_or_lowercase(mut self, word: &'static str) -> Self {
+    pub fn then_exact_word(mut self, word: &str) -> Self {
+        let word: Vec<char> = word.chars().collect();
+
         self.token_patterns
-            .push(Box::new(|tok: &Token, source: &[char]| {
+            .push(Box::new(move |tok: &Token, source: &[char]| {
                 if !tok.kind.is_word() {
                     return false;
                 }
 
-                let tok_chars = tok.span.get_content(source).to_lower();
+                tok.span.get_content(source) == word.as_slice()
+            }));
+        self
+    }
 
-                let mut w_char_count = 0;
-                for (i, w_char) in word.to_lowercase().chars().enumerate() {
-                    w_char_count += 1;
+    pub fn then_exact_word_or_lowercase(mut self, word: &str) -> Self {
+        let word: Vec<char> = word.to_lowercase().chars().collect();
 
-                    if tok_chars.get(i).cloned() != Some(w_char) {
-                        return false;
-                    }
+        self.token_patterns
+            .push(Box::new(move |tok: &Token, source: &[char]| {
+                if !tok.kind.is_word() {
+                    return false;
                 }
 
-                w_char_count == tok_chars.len()
+                tok.span.get_content(source).to_lower().as_ref() == word.as_slice()
             }));
         self
     }
@@ -138,22 +151,43 @@ impl SequencePattern {
         self.token_patterns.push(pat);
         self
     }
+
+    /// Match a pattern and record the tokens it matched under a name.
+    /// See [`Captures`].
+    pub fn then_capture(mut self, name: &str, pat: Box<dyn Pattern>) -> Self {
+        self.token_patterns.push(Box::new(Capture::new(name, pat)));
+        self
+    }
 }
 
 impl Pattern for SequencePattern {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let mut found = Captures::default();
         let mut tok_cursor = 0;
 
         for pat in self.token_patterns.iter() {
-            let match_length = pat.matches(&tokens[tok_cursor..], source);
+            let mut inner = Captures::default();
+            let match_length =
+                pat.matches_in_context(context.advanced_by(tok_cursor), source, &mut inner);
 
             if match_length == 0 {
                 return 0;
             }
 
+            found.append_offset(&mut inner, tok_cursor);
             tok_cursor += match_length;
         }
 
+        captures.append_offset(&mut found, 0);
         tok_cursor
     }
 }
@@ -194,6 +228,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn matches_phrases() {
+        let pat = SequencePattern::from_phrase("master/slave replication");
+        let doc = Document::new_plain_english_curated("Master/Slave  replication");
+
+        assert_eq!(
+            pat.matches(doc.get_tokens(), doc.get_source()),
+            doc.get_tokens().len()
+        );
+    }
+
+    #[test]
+    fn matches_exact_phrases() {
+        let pat = SequencePattern::from_exact_phrase("GitHub Actions");
+
+        let doc = Document::new_plain_english_curated("GitHub Actions");
+        assert_eq!(
+            pat.matches(doc.get_tokens(), doc.get_source()),
+            doc.get_tokens().len()
+        );
+
+        let doc = Document::new_plain_english_curated("Github actions");
+        assert_eq!(pat.matches(doc.get_tokens(), doc.get_source()), 0);
+    }
+
     #[test]
     fn matches_sets() {
         let mut pronouns = HashSet::new();
@@ -1,6 +1,6 @@
 use hashbrown::HashMap;
 
-use super::Pattern;
+use super::{Captures, MatchContext, Pattern};
 use crate::{Token, TokenKind};
 
 pub struct TokenKindPatternGroup {
@@ -10,7 +10,16 @@ pub struct TokenKindPatternGroup {
 
 impl Pattern for TokenKindPatternGroup {
     fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
-        let Some(first_kind) = &tokens.first().map(|t| t.kind) else {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let Some(first_kind) = &context.window().first().map(|t| t.kind) else {
             return 0;
         };
 
@@ -18,6 +27,6 @@ impl Pattern for TokenKindPatternGroup {
             return 0;
         };
 
-        pattern.matches(tokens, source)
+        pattern.matches_in_context(context, source, captures)
     }
 }
@@ -1,7 +1,7 @@
 use hashbrown::HashMap;
 
 use super::naive_pattern_group::NaivePatternGroup;
-use super::{Pattern, SequencePattern};
+use super::{Captures, MatchContext, Pattern, SequencePattern};
 use crate::CharString;
 
 /// A pattern collection to look for patterns that start with a specific
@@ -28,7 +28,7 @@ impl WordPatternGroup<NaivePatternGroup> {
     }
 
     /// Add a pattern that matches just a word on its own, without anything else required to match.
-    pub fn add_word(&mut self, word: &'static str) {
+    pub fn add_word(&mut self, word: &str) {
         self.add(
             word,
             Box::new(SequencePattern::default().then_exact_word(word)),
@@ -41,7 +41,16 @@ where
     P: Pattern,
 {
     fn matches(&self, tokens: &[crate::Token], source: &[char]) -> usize {
-        let Some(first) = tokens.first() else {
+        self.matches_in_context(MatchContext::new(tokens), source, &mut Captures::default())
+    }
+
+    fn matches_in_context(
+        &self,
+        context: MatchContext,
+        source: &[char],
+        captures: &mut Captures,
+    ) -> usize {
+        let Some(first) = context.window().first() else {
             return 0;
         };
 
@@ -54,6 +63,6 @@ where
             return 0;
         };
 
-        inner_pattern.matches(tokens, source)
+        inner_pattern.matches_in_context(context, source, captures)
     }
 }
@@ -0,0 +1,420 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use crate::{CharString, CharStringExt, Token, TokenKind, WordMetadata};
+
+/// A coarse part of speech, as assigned by the [`PosTagger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PosTag {
+    Noun,
+    Pronoun,
+    Verb,
+    Adjective,
+    Adverb,
+    Conjunction,
+    Determiner,
+    Preposition,
+    Number,
+    Punctuation,
+    /// Anything else, like URLs or inline code.
+    Other,
+}
+
+const TAG_COUNT: usize = 11;
+
+const ALL_TAGS: [PosTag; TAG_COUNT] = [
+    PosTag::Noun,
+    PosTag::Pronoun,
+    PosTag::Verb,
+    PosTag::Adjective,
+    PosTag::Adverb,
+    PosTag::Conjunction,
+    PosTag::Determiner,
+    PosTag::Preposition,
+    PosTag::Number,
+    PosTag::Punctuation,
+    PosTag::Other,
+];
+
+/// The tags an unknown word may take when the dictionary knows nothing about
+/// it either.
+const OPEN_TAGS: [PosTag; 4] = [
+    PosTag::Noun,
+    PosTag::Verb,
+    PosTag::Adjective,
+    PosTag::Adverb,
+];
+
+/// How much weight the dictionary's opinion has compared to a single
+/// occurrence in the corpus.
+const DICTIONARY_WEIGHT: f32 = 0.2;
+
+/// The longest word suffix used to guess the tags of unknown words.
+const MAX_SUFFIX_LEN: usize = 3;
+
+impl PosTag {
+    fn from_corpus_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "NOUN" => Self::Noun,
+            "PRON" => Self::Pronoun,
+            "VERB" => Self::Verb,
+            "ADJ" => Self::Adjective,
+            "ADV" => Self::Adverb,
+            "CONJ" => Self::Conjunction,
+            "DET" => Self::Determiner,
+            "PREP" => Self::Preposition,
+            "NUM" => Self::Number,
+            "PUNCT" => Self::Punctuation,
+            _ => return None,
+        })
+    }
+
+    /// The tags the dictionary allows for a word.
+    fn from_metadata(metadata: &WordMetadata) -> Vec<Self> {
+        let mut tags = Vec::new();
+
+        if metadata.is_noun() {
+            tags.push(Self::Noun);
+        }
+        if metadata.is_pronoun_noun() {
+            tags.push(Self::Pronoun);
+        }
+        if metadata.is_verb() {
+            tags.push(Self::Verb);
+        }
+        if metadata.is_adjective() {
+            tags.push(Self::Adjective);
+        }
+        if metadata.is_adverb() {
+            tags.push(Self::Adverb);
+        }
+        if metadata.is_conjunction() {
+            tags.push(Self::Conjunction);
+        }
+        if metadata.article {
+            tags.push(Self::Determiner);
+        }
+        if metadata.preposition {
+            tags.push(Self::Preposition);
+        }
+
+        tags
+    }
+}
+
+lazy_static! {
+    static ref CURATED: Arc<PosTagger> =
+        Arc::new(PosTagger::train(include_str!("../pos_corpus.txt")));
+}
+
+/// A hidden Markov model that assigns a part of speech to each token of a
+/// sentence, taking the surrounding tokens into account.
+///
+/// Words seen during training are tagged based on how they were used there.
+/// Other words fall back to the categories in their [`WordMetadata`] and
+/// the tags of words with the same suffix.
+pub struct PosTagger {
+    /// The log-probability of each tag given the previous one.
+    /// The last row is for the start of a sentence.
+    transitions: [[f32; TAG_COUNT]; TAG_COUNT + 1],
+    /// The log-probability of each tag overall.
+    priors: [f32; TAG_COUNT],
+    /// How often each (lowercase) word appeared with each tag.
+    words: HashMap<CharString, [u32; TAG_COUNT]>,
+    /// How often the words with each suffix appeared with each tag.
+    suffixes: HashMap<CharString, [u32; TAG_COUNT]>,
+}
+
+impl PosTagger {
+    /// Get the tagger trained on the corpus included in the Harper binary.
+    pub fn curated() -> Arc<Self> {
+        (*CURATED).clone()
+    }
+
+    /// Train a tagger on a corpus of sentences.
+    ///
+    /// Each line is a sentence of whitespace-separated `word/TAG` pairs,
+    /// where the tags are `NOUN`, `PRON`, `VERB`, `ADJ`, `ADV`, `CONJ`,
+    /// `DET`, `PREP`, `NUM` or `PUNCT`.
+    /// Blank lines, lines starting with `#` and malformed pairs are skipped.
+    pub fn train(corpus: &str) -> Self {
+        let mut transition_counts = [[0u32; TAG_COUNT]; TAG_COUNT + 1];
+        let mut tag_counts = [0u32; TAG_COUNT];
+        let mut words: HashMap<CharString, [u32; TAG_COUNT]> = HashMap::new();
+        let mut suffixes: HashMap<CharString, [u32; TAG_COUNT]> = HashMap::new();
+
+        for line in corpus.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut prev = TAG_COUNT;
+
+            for pair in line.split_whitespace() {
+                let Some((word, tag)) = pair.rsplit_once('/') else {
+                    continue;
+                };
+                let Some(tag) = PosTag::from_corpus_name(tag) else {
+                    continue;
+                };
+
+                let word: CharString = word.chars().collect::<Vec<_>>().to_lower();
+
+                transition_counts[prev][tag as usize] += 1;
+                tag_counts[tag as usize] += 1;
+                words.entry(word.clone()).or_default()[tag as usize] += 1;
+
+                for suffix in suffixes_of(&word) {
+                    suffixes.entry(suffix).or_default()[tag as usize] += 1;
+                }
+
+                prev = tag as usize;
+            }
+        }
+
+        let mut transitions = [[0.; TAG_COUNT]; TAG_COUNT + 1];
+
+        for (row, counts) in transitions.iter_mut().zip(transition_counts.iter()) {
+            let total: u32 = counts.iter().sum();
+
+            // Add-one smoothing, so no sequence of tags is impossible.
+            for (prob, count) in row.iter_mut().zip(counts) {
+                *prob = ((*count as f32 + 1.) / (total as f32 + TAG_COUNT as f32)).ln();
+            }
+        }
+
+        let total: u32 = tag_counts.iter().sum();
+        let mut priors = [0.; TAG_COUNT];
+
+        for (prior, count) in priors.iter_mut().zip(tag_counts) {
+            *prior = ((count as f32 + 1.) / (total as f32 + TAG_COUNT as f32)).ln();
+        }
+
+        Self {
+            transitions,
+            priors,
+            words,
+            suffixes,
+        }
+    }
+
+    /// Assign a tag to each of the provided tokens, which should be the
+    /// non-whitespace tokens of a single sentence.
+    pub fn tag_sentence(&self, tokens: &[Token], source: &[char]) -> Vec<PosTag> {
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+
+        let emissions: Vec<Vec<(PosTag, f32)>> = tokens
+            .iter()
+            .map(|tok| self.emission_scores(tok, source))
+            .collect();
+
+        // The Viterbi algorithm: for each tag of each token, the score of the
+        // best sequence ending in it, and the index of the previous tag.
+        let mut scores: Vec<Vec<(f32, usize)>> = Vec::with_capacity(tokens.len());
+
+        scores.push(
+            emissions[0]
+                .iter()
+                .map(|(tag, score)| (self.transitions[TAG_COUNT][*tag as usize] + score, 0))
+                .collect(),
+        );
+
+        for i in 1..tokens.len() {
+            let prev_emissions = &emissions[i - 1];
+            let prev_scores = &scores[i - 1];
+
+            let row = emissions[i]
+                .iter()
+                .map(|(tag, emission)| {
+                    prev_emissions
+                        .iter()
+                        .zip(prev_scores)
+                        .enumerate()
+                        .map(|(prev_idx, ((prev_tag, _), (prev_score, _)))| {
+                            (
+                                prev_score
+                                    + self.transitions[*prev_tag as usize][*tag as usize]
+                                    + emission,
+                                prev_idx,
+                            )
+                        })
+                        .max_by(|a, b| a.0.total_cmp(&b.0))
+                        .unwrap()
+                })
+                .collect();
+
+            scores.push(row);
+        }
+
+        let mut best = scores
+            .last()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1 .0.total_cmp(&b.1 .0))
+            .map(|(idx, _)| idx)
+            .unwrap();
+
+        let mut tags = vec![PosTag::Other; tokens.len()];
+
+        for i in (0..tokens.len()).rev() {
+            tags[i] = emissions[i][best].0;
+            best = scores[i][best].1;
+        }
+
+        tags
+    }
+
+    /// The possible tags of a token, each with the log of how much more
+    /// likely the token is to appear with that tag than with any other.
+    fn emission_scores(&self, token: &Token, source: &[char]) -> Vec<(PosTag, f32)> {
+        let metadata = match token.kind {
+            TokenKind::Word(metadata) => metadata,
+            TokenKind::Punctuation(_) => return vec![(PosTag::Punctuation, 0.)],
+            TokenKind::Number(..) => return vec![(PosTag::Number, 0.)],
+            _ => return vec![(PosTag::Other, 0.)],
+        };
+
+        let word = token.span.get_content(source).to_lower();
+        let allowed = PosTag::from_metadata(&metadata);
+
+        let weights: Vec<(PosTag, f32)> = if let Some(counts) = self.words.get(&word) {
+            ALL_TAGS
+                .iter()
+                .map(|tag| {
+                    let from_dict = if allowed.contains(tag) {
+                        DICTIONARY_WEIGHT
+                    } else {
+                        0.
+                    };
+
+                    (*tag, counts[*tag as usize] as f32 + from_dict)
+                })
+                .collect()
+        } else {
+            let counts = suffixes_of(&word)
+                .into_iter()
+                .find_map(|suffix| self.suffixes.get(&suffix))
+                .copied()
+                .unwrap_or_default();
+
+            let candidates = if allowed.is_empty() {
+                OPEN_TAGS.as_slice()
+            } else {
+                allowed.as_slice()
+            };
+
+            candidates
+                .iter()
+                .map(|tag| (*tag, counts[*tag as usize] as f32 + 1.))
+                .collect()
+        };
+
+        let total: f32 = weights.iter().map(|(_, w)| w).sum();
+
+        weights
+            .into_iter()
+            .filter(|(_, weight)| *weight > 0.)
+            .map(|(tag, weight)| (tag, (weight / total).ln() - self.priors[tag as usize]))
+            .collect()
+    }
+}
+
+/// The suffixes of a word used to guess its tag, longest first.
+fn suffixes_of(word: &[char]) -> Vec<CharString> {
+    (1..=MAX_SUFFIX_LEN.min(word.len().saturating_sub(1)))
+        .rev()
+        .map(|len| word[word.len() - len..].iter().copied().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PosTag, PosTagger};
+    use crate::Document;
+
+    fn tag(text: &str) -> Vec<(String, PosTag)> {
+        let document = Document::new_plain_english_curated(text);
+        let tokens: Vec<_> = document
+            .tokens()
+            .filter(|t| !t.kind.is_whitespace())
+            .collect();
+
+        let tags = PosTagger::curated().tag_sentence(&tokens, document.get_source());
+
+        tokens
+            .iter()
+            .map(|t| document.get_span_content_str(t.span))
+            .zip(tags)
+            .collect()
+    }
+
+    fn tag_of(text: &str, word: &str) -> PosTag {
+        tag(text)
+            .into_iter()
+            .find(|(w, _)| w == word)
+            .map(|(_, tag)| tag)
+            .unwrap()
+    }
+
+    #[test]
+    fn tags_record_by_context() {
+        assert_eq!(tag_of("He holds the record.", "record"), PosTag::Noun);
+        assert_eq!(tag_of("They record every song.", "record"), PosTag::Verb);
+    }
+
+    #[test]
+    fn tags_lead_by_context() {
+        assert_eq!(tag_of("Old paint contains lead.", "lead"), PosTag::Noun);
+        assert_eq!(tag_of("She will lead the team.", "lead"), PosTag::Verb);
+    }
+
+    #[test]
+    fn tags_unknown_adverbs_by_suffix() {
+        assert_eq!(tag_of("The tests ran swiftly.", "swiftly"), PosTag::Adverb);
+    }
+
+    #[test]
+    fn keeps_dictionary_categories() {
+        let document = Document::new_plain_english_curated("They record every song.");
+        let record = document
+            .tokens()
+            .find(|t| document.get_span_content_str(t.span) == "record")
+            .unwrap();
+        let metadata = record.kind.as_word().unwrap();
+
+        assert_eq!(metadata.pos_tag, Some(PosTag::Verb));
+        assert!(metadata.is_noun());
+        assert!(!metadata.narrowed().is_noun());
+        assert!(metadata.narrowed().is_verb());
+    }
+
+    #[test]
+    fn marks_articles_and_prepositions() {
+        let document = Document::new_plain_english_curated("The cat sat on the mat.");
+        let metadata: Vec<_> = document
+            .tokens()
+            .filter_map(|t| t.kind.as_word().copied())
+            .collect();
+
+        assert!(metadata[0].article);
+        assert!(!metadata[1].article && !metadata[1].preposition);
+        assert!(metadata[3].preposition);
+        assert_eq!(metadata[3].pos_tag, Some(PosTag::Preposition));
+    }
+
+    #[test]
+    fn tags_punctuation_and_numbers() {
+        let tags = tag("I have 3 cats.");
+
+        assert_eq!(tags[2].1, PosTag::Number);
+        assert_eq!(tags[4].1, PosTag::Punctuation);
+    }
+}
@@ -17,6 +17,9 @@ pub enum Punctuation {
     /// `.`
     #[default]
     Period,
+    /// A `.` that ends an abbreviation, like the one in "Dr.", rather than a
+    /// sentence.
+    AbbreviationPeriod,
     /// `!`
     Bang,
     /// `?`
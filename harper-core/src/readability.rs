@@ -0,0 +1,229 @@
+//! Common readability metrics, like the Flesch reading ease and the Gunning
+//! fog index.
+//!
+//! ```
+//! use harper_core::readability::ReadabilityStats;
+//! use harper_core::Document;
+//!
+//! let document = Document::new_plain_english_curated("The cat sat on the mat.");
+//! let stats = ReadabilityStats::from_document(&document);
+//!
+//! assert_eq!(stats.sentences, 1);
+//! assert_eq!(stats.words, 6);
+//! assert!(stats.flesch_reading_ease() > 100.);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Document, Token, TokenStringExt};
+
+/// Word and sentence counts of a piece of text, from which the readability
+/// metrics are derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ReadabilityStats {
+    /// The number of sentences that contain at least one word.
+    pub sentences: usize,
+    pub words: usize,
+    pub syllables: usize,
+    /// The number of words with three or more syllables, not counting proper
+    /// nouns.
+    pub complex_words: usize,
+}
+
+impl ReadabilityStats {
+    pub fn from_document(document: &Document) -> Self {
+        Self::from_tokens(document.get_tokens(), document.get_source())
+    }
+
+    pub fn from_tokens(tokens: &[Token], source: &[char]) -> Self {
+        let mut stats = Self::default();
+
+        for sentence in tokens.iter_sentences() {
+            let mut has_words = false;
+
+            for word in sentence.iter_words() {
+                has_words = true;
+
+                let syllables = count_syllables(word.span.get_content(source));
+
+                stats.words += 1;
+                stats.syllables += syllables;
+
+                let is_proper = word.kind.as_word().unwrap().is_proper_noun();
+
+                if syllables >= 3 && !is_proper {
+                    stats.complex_words += 1;
+                }
+            }
+
+            if has_words {
+                stats.sentences += 1;
+            }
+        }
+
+        stats
+    }
+
+    /// The average number of words in each sentence.
+    pub fn average_sentence_length(&self) -> f32 {
+        if self.sentences == 0 {
+            return 0.;
+        }
+
+        self.words as f32 / self.sentences as f32
+    }
+
+    /// The average number of syllables in each word.
+    pub fn average_syllables_per_word(&self) -> f32 {
+        if self.words == 0 {
+            return 0.;
+        }
+
+        self.syllables as f32 / self.words as f32
+    }
+
+    /// The [Flesch reading ease](https://en.wikipedia.org/wiki/Flesch%E2%80%93Kincaid_readability_tests#Flesch_reading_ease)
+    /// of the text.
+    /// Higher scores are easier to read: most people can follow text that
+    /// scores above 60.
+    pub fn flesch_reading_ease(&self) -> f32 {
+        if self.words == 0 {
+            return 0.;
+        }
+
+        206.835 - 1.015 * self.average_sentence_length() - 84.6 * self.average_syllables_per_word()
+    }
+
+    /// The [Flesch-Kincaid grade level](https://en.wikipedia.org/wiki/Flesch%E2%80%93Kincaid_readability_tests#Flesch%E2%80%93Kincaid_grade_level)
+    /// of the text, which approximates the U.S. school grade needed to
+    /// understand it.
+    pub fn flesch_kincaid_grade(&self) -> f32 {
+        if self.words == 0 {
+            return 0.;
+        }
+
+        0.39 * self.average_sentence_length() + 11.8 * self.average_syllables_per_word() - 15.59
+    }
+
+    /// The [Gunning fog index](https://en.wikipedia.org/wiki/Gunning_fog_index)
+    /// of the text, which estimates the years of formal education needed to
+    /// understand it on first reading.
+    pub fn gunning_fog(&self) -> f32 {
+        if self.words == 0 {
+            return 0.;
+        }
+
+        0.4 * (self.average_sentence_length()
+            + 100. * self.complex_words as f32 / self.words as f32)
+    }
+}
+
+/// Estimate the number of syllables in an English word.
+///
+/// This counts groups of vowels, after accounting for common silent endings
+/// like the "e" in "make" and the "ed" in "jumped".
+/// It is usually right, but English spelling makes it impossible to always
+/// be.
+pub fn count_syllables(word: &[char]) -> usize {
+    let mut letters: Vec<char> = word
+        .iter()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+
+    if letters.is_empty() {
+        return 0;
+    }
+
+    if letters.len() <= 3 {
+        return 1;
+    }
+
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    // Silent endings, like "makes", "jumped" and "make".
+    // Endings that follow sounds that need their own syllable, like "boxes",
+    // "wanted" and "table", are kept.
+    match letters.as_slice() {
+        [.., c, 'e', 's'] if !is_vowel(*c) && !"sxzhcgl".contains(*c) => {
+            letters.truncate(letters.len() - 2)
+        }
+        [.., c, 'e', 'd'] if !"td".contains(*c) => letters.truncate(letters.len() - 2),
+        [.., c, 'e'] if !is_vowel(*c) && *c != 'l' => letters.truncate(letters.len() - 1),
+        _ => (),
+    }
+
+    if letters.first() == Some(&'y') {
+        letters.remove(0);
+    }
+
+    let groups = letters
+        .iter()
+        .zip(std::iter::once(&' ').chain(letters.iter()))
+        .filter(|(c, prev)| is_vowel(**c) && !is_vowel(**prev))
+        .count();
+
+    groups.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_syllables, ReadabilityStats};
+    use crate::Document;
+
+    fn syllables(word: &str) -> usize {
+        count_syllables(&word.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn counts_syllables() {
+        assert_eq!(syllables("the"), 1);
+        assert_eq!(syllables("hello"), 2);
+        assert_eq!(syllables("syllable"), 3);
+        assert_eq!(syllables("readability"), 5);
+        assert_eq!(syllables("beautiful"), 3);
+    }
+
+    #[test]
+    fn handles_silent_endings() {
+        assert_eq!(syllables("make"), 1);
+        assert_eq!(syllables("makes"), 1);
+        assert_eq!(syllables("jumped"), 1);
+        assert_eq!(syllables("wanted"), 2);
+        assert_eq!(syllables("boxes"), 2);
+        assert_eq!(syllables("table"), 2);
+    }
+
+    #[test]
+    fn counts_sentences_and_words() {
+        let document =
+            Document::new_plain_english_curated("The cat sat on the mat. It was a very happy cat.");
+        let stats = ReadabilityStats::from_document(&document);
+
+        assert_eq!(stats.sentences, 2);
+        assert_eq!(stats.words, 12);
+        assert_eq!(stats.average_sentence_length(), 6.);
+    }
+
+    #[test]
+    fn complex_text_has_higher_grade() {
+        let simple = ReadabilityStats::from_document(&Document::new_plain_english_curated(
+            "The dog ran. The dog was fast. We like the dog.",
+        ));
+        let complex = ReadabilityStats::from_document(&Document::new_plain_english_curated(
+            "Institutional considerations necessitate comprehensive organizational restructuring, particularly regarding interdepartmental communication.",
+        ));
+
+        assert!(complex.flesch_kincaid_grade() > simple.flesch_kincaid_grade());
+        assert!(complex.gunning_fog() > simple.gunning_fog());
+        assert!(complex.flesch_reading_ease() < simple.flesch_reading_ease());
+    }
+
+    #[test]
+    fn empty_document_scores_zero() {
+        let stats = ReadabilityStats::from_document(&Document::new_plain_english_curated(""));
+
+        assert_eq!(stats, ReadabilityStats::default());
+        assert_eq!(stats.flesch_kincaid_grade(), 0.);
+    }
+}
@@ -6,7 +6,7 @@ use super::affix_replacement::AffixReplacement;
 use super::expansion::{Expansion, HumanReadableExpansion};
 use super::word_list::MarkedWord;
 use super::Error;
-use crate::{CharString, Span, WordMetadata};
+use crate::{CharString, DialectFlags, Span, WordMetadata};
 
 #[derive(Debug, Clone)]
 pub struct AttributeList {
@@ -34,16 +34,55 @@ impl AttributeList {
         &self,
         word: MarkedWord,
         dest: &mut HashMap<CharString, WordMetadata>,
+    ) {
+        self.expand_marked_word_in_dialects(word, None, dest)
+    }
+
+    /// Like [`Self::expand_marked_word`], but for words derived from a parent
+    /// that belongs to the given dialects.
+    fn expand_marked_word_in_dialects(
+        &self,
+        word: MarkedWord,
+        parent_dialects: Option<DialectFlags>,
+        dest: &mut HashMap<CharString, WordMetadata>,
     ) {
         dest.reserve(word.attributes.len() + 1);
         let mut gifted_metadata = WordMetadata::default();
 
+        for attr in &word.attributes {
+            if let Some(expansion) = self.affixes.get(attr) {
+                gifted_metadata.append(&expansion.gifts_metadata);
+            }
+        }
+
+        // Words that are not marked otherwise are spelled the same in every
+        // dialect. Marking them explicitly means they win over
+        // dialect-specific words with the same spelling, like "analyses".
+        let dialects = gifted_metadata
+            .dialects
+            .or(parent_dialects)
+            .unwrap_or(DialectFlags::ALL);
+        gifted_metadata.dialects = Some(dialects);
+
+        // Some expansions depend on whether the parent word is a verb, like the
+        // "s" in "shows", which also marks the third-person singular.
+        let is_verb = gifted_metadata.is_verb();
+
         for attr in &word.attributes {
             let Some(expansion) = self.affixes.get(attr) else {
                 continue;
             };
 
-            gifted_metadata.append(&expansion.gifts_metadata);
+            let mut adds_metadata = expansion.adds_metadata;
+            // Words derived from a spelling belong to the same dialects, like
+            // "colours" from "colour".
+            adds_metadata.dialects = Some(dialects);
+
+            if is_verb {
+                gifted_metadata.append(&expansion.gifts_verb_metadata);
+                adds_metadata.append(&expansion.adds_verb_metadata);
+            }
+
             let mut new_words: HashMap<CharString, WordMetadata> = HashMap::new();
 
             for replacement in &expansion.replacements {
@@ -51,9 +90,9 @@ impl AttributeList {
                     Self::apply_replacement(replacement, &word.letters, expansion.suffix)
                 {
                     if let Some(val) = new_words.get_mut(&replaced) {
-                        val.append(&expansion.adds_metadata);
+                        val.append(&adds_metadata);
                     } else {
-                        new_words.insert(replaced, expansion.adds_metadata);
+                        new_words.insert(replaced, adds_metadata);
                     }
                 }
             }
@@ -71,11 +110,12 @@ impl AttributeList {
                 }
 
                 for (new_word, metadata) in new_words {
-                    self.expand_marked_word(
+                    self.expand_marked_word_in_dialects(
                         MarkedWord {
                             letters: new_word.clone(),
                             attributes: opp_attr.clone(),
                         },
+                        Some(dialects),
                         dest,
                     );
                     let t_metadata = dest.get_mut(&new_word).unwrap();
@@ -107,9 +147,36 @@ impl AttributeList {
         words: impl IntoIterator<Item = MarkedWord>,
         dest: &mut HashMap<CharString, WordMetadata>,
     ) {
+        let mut listed: HashMap<CharString, DialectFlags> = HashMap::new();
+
         for word in words {
+            let dialects = self.marked_dialects(&word);
+
+            listed
+                .entry(word.letters.clone())
+                .and_modify(|flags| *flags = flags.or(&dialects))
+                .or_insert(dialects);
+
             self.expand_marked_word(word, dest);
         }
+
+        // Words that are listed themselves belong to the dialects they are
+        // marked with, even if they can also be derived from a word in
+        // another dialect (like "traveled" from "travel").
+        for (word, dialects) in listed {
+            if let Some(metadata) = dest.get_mut(&word) {
+                metadata.dialects = Some(dialects);
+            }
+        }
+    }
+
+    /// Get the dialects a word is marked as belonging to.
+    fn marked_dialects(&self, word: &MarkedWord) -> DialectFlags {
+        word.attributes
+            .iter()
+            .filter_map(|attr| self.affixes.get(attr)?.gifts_metadata.dialects)
+            .reduce(|a, b| a.or(&b))
+            .unwrap_or(DialectFlags::ALL)
     }
 
     fn apply_replacement(
@@ -16,6 +16,12 @@ pub struct Expansion {
     /// When the expansion is applied, the __parent__ word will have this
     /// metadata appended to it.
     pub gifts_metadata: WordMetadata,
+    /// Like [`Self::adds_metadata`], but only applied when the parent word is
+    /// a verb.
+    pub adds_verb_metadata: WordMetadata,
+    /// Like [`Self::gifts_metadata`], but only applied when the parent word is
+    /// a verb.
+    pub gifts_verb_metadata: WordMetadata,
 }
 
 impl Expansion {
@@ -30,6 +36,8 @@ impl Expansion {
                 .collect(),
             adds_metadata: self.adds_metadata,
             gifts_metadata: self.gifts_metadata,
+            adds_verb_metadata: self.adds_verb_metadata,
+            gifts_verb_metadata: self.gifts_verb_metadata,
         }
     }
 }
@@ -41,6 +49,10 @@ pub struct HumanReadableExpansion {
     pub replacements: Vec<HumanReadableAffixReplacement>,
     pub adds_metadata: WordMetadata,
     pub gifts_metadata: WordMetadata,
+    #[serde(default)]
+    pub adds_verb_metadata: WordMetadata,
+    #[serde(default)]
+    pub gifts_verb_metadata: WordMetadata,
 }
 
 impl HumanReadableExpansion {
@@ -57,6 +69,8 @@ impl HumanReadableExpansion {
             replacements,
             adds_metadata: self.adds_metadata,
             gifts_metadata: self.gifts_metadata,
+            adds_verb_metadata: self.adds_verb_metadata,
+            gifts_verb_metadata: self.gifts_verb_metadata,
         })
     }
 }
@@ -34,7 +34,7 @@ mod tests {
     use super::word_list::parse_word_list;
     use super::{parse_default_attribute_list, parse_default_word_list};
     use crate::spell::hunspell::attribute_list::HumanReadableAttributeList;
-    use crate::{CharString, WordMetadata};
+    use crate::{CharString, Dialect, WordMetadata};
 
     pub const TEST_WORD_LIST: &str = "3\nhello\ntry/B\nwork/AB";
 
@@ -230,6 +230,50 @@ mod tests {
         assert!(is.unwrap().is_linking_verb());
     }
 
+    #[test]
+    fn marks_past_participles() {
+        let expanded = build_expanded();
+
+        for word in ["walked", "eaten", "stopped"] {
+            assert!(expanded[&split(word)].is_past_participle_verb(), "{word}");
+        }
+
+        assert!(!expanded[&split("walk")].is_past_participle_verb());
+        assert!(expanded[&split("quickly")].is_adverb());
+    }
+
+    #[test]
+    fn header_counts_every_entry() {
+        let mut lines = include_str!("../../../dictionary.dict").lines();
+        let count: usize = lines.next().unwrap().parse().unwrap();
+
+        assert_eq!(count, lines.count());
+    }
+
+    #[test]
+    fn derived_words_keep_dialect() {
+        let expanded = build_expanded();
+
+        let colours = expanded.get(&split("colours")).unwrap();
+        assert!(colours.is_in_dialect(Dialect::British));
+        assert!(!colours.is_in_dialect(Dialect::American));
+
+        // Also the plural of "analysis", so it is shared.
+        let analyses = expanded.get(&split("analyses")).unwrap();
+        assert!(analyses.is_in_dialect(Dialect::British));
+        assert!(analyses.is_in_dialect(Dialect::American));
+    }
+
+    #[test]
+    fn listed_words_keep_dialect() {
+        let expanded = build_expanded();
+
+        // Also derived from "travel", which is shared.
+        let traveled = expanded.get(&split("traveled")).unwrap();
+        assert!(traveled.is_in_dialect(Dialect::American));
+        assert!(!traveled.is_in_dialect(Dialect::British));
+    }
+
     fn split(text: &str) -> CharString {
         text.chars().collect()
     }
@@ -0,0 +1,387 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Span, Token};
+
+/// A region of a document in which some (or all) lints should be dropped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// The characters covered by the suppression.
+    pub span: Span,
+    /// The rules that are suppressed.
+    /// If [`None`], all rules are suppressed.
+    pub rules: Option<Vec<String>>,
+}
+
+impl Suppression {
+    /// Whether the suppression applies to a lint with a given span, produced
+    /// by a given rule.
+    pub fn applies_to(&self, span: Span, rule: &str) -> bool {
+        if !self.span.overlaps_with(span) {
+            return false;
+        }
+
+        match &self.rules {
+            None => true,
+            Some(rules) => rules.iter().any(|r| rule_names_match(r, rule)),
+        }
+    }
+}
+
+/// The set of inline suppression directives found in a document.
+///
+/// Directives can be placed anywhere the parser considers part of the
+/// document. That includes HTML comments in Markdown, code comments parsed by
+/// `harper-comments` and plain text.
+///
+/// The following directives are supported:
+///
+/// - `harper-ignore-line [rules...]`: suppress lints on the same line.
+/// - `harper-ignore-next-line [rules...]`: suppress lints on the following
+///   line.
+/// - `harper-disable [rules...]`: suppress lints until a matching
+///   `harper-enable` or the end of the document.
+/// - `harper-enable [rules...]`: end a region started by `harper-disable`. A
+///   bare `harper-enable` ends every open region.
+///
+/// When no rules are listed, every rule is suppressed.
+/// Rule names are compared without regard to case, underscores or hyphens,
+/// so `an_a`, `an-a` and `AnA` are equivalent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppressions {
+    suppressions: Vec<Suppression>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveKind {
+    IgnoreLine,
+    IgnoreNextLine,
+    Disable,
+    Enable,
+}
+
+impl DirectiveKind {
+    const ALL: [(&'static str, Self); 4] = [
+        ("ignore-next-line", Self::IgnoreNextLine),
+        ("ignore-line", Self::IgnoreLine),
+        ("disable", Self::Disable),
+        ("enable", Self::Enable),
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Directive {
+    kind: DirectiveKind,
+    rules: Option<Vec<String>>,
+}
+
+impl Suppressions {
+    /// Locate the suppression directives in a source text.
+    ///
+    /// Only directives that are covered by one of the provided tokens are
+    /// considered, so text the parser ignored (like string literals in source
+    /// code) cannot suppress anything.
+    pub fn new(source: &[char], tokens: &[Token]) -> Self {
+        let lines = line_spans(source);
+
+        let mut suppressions = Vec::new();
+        // Regions opened by `harper-disable` that have yet to be closed.
+        let mut open: Vec<(usize, Option<Vec<String>>)> = Vec::new();
+
+        for (line_idx, line) in lines.iter().enumerate() {
+            let Some((directive_start, directive)) = find_directive(source, *line) else {
+                continue;
+            };
+
+            if !is_covered(tokens, directive_start) {
+                continue;
+            }
+
+            // The directive itself should never be linted.
+            suppressions.push(Suppression {
+                span: *line,
+                rules: None,
+            });
+
+            match directive.kind {
+                DirectiveKind::IgnoreLine => suppressions.push(Suppression {
+                    span: *line,
+                    rules: directive.rules,
+                }),
+                DirectiveKind::IgnoreNextLine => {
+                    if let Some(next) = lines.get(line_idx + 1) {
+                        suppressions.push(Suppression {
+                            span: *next,
+                            rules: directive.rules,
+                        })
+                    }
+                }
+                DirectiveKind::Disable => open.push((line.end, directive.rules)),
+                DirectiveKind::Enable => {
+                    let mut still_open = Vec::new();
+
+                    for (start, rules) in open.drain(..) {
+                        let span = Span::new(start, line.start.max(start));
+
+                        match (&directive.rules, rules) {
+                            (None, rules) => suppressions.push(Suppression { span, rules }),
+                            (Some(enabled), Some(disabled)) => {
+                                let (closed, remaining): (Vec<_>, Vec<_>) = disabled
+                                    .into_iter()
+                                    .partition(|d| enabled.iter().any(|e| rule_names_match(d, e)));
+
+                                if !closed.is_empty() {
+                                    suppressions.push(Suppression {
+                                        span,
+                                        rules: Some(closed),
+                                    });
+                                }
+
+                                if !remaining.is_empty() {
+                                    still_open.push((start, Some(remaining)));
+                                }
+                            }
+                            // Named rules cannot be re-enabled inside a region
+                            // that disables everything.
+                            (Some(_), None) => still_open.push((start, None)),
+                        }
+                    }
+
+                    open = still_open;
+                }
+            }
+        }
+
+        for (start, rules) in open {
+            suppressions.push(Suppression {
+                span: Span::new(start, source.len().max(start)),
+                rules,
+            });
+        }
+
+        Self { suppressions }
+    }
+
+    /// Whether a lint with a given span, produced by a given rule, falls inside
+    /// a suppressed region.
+    pub fn is_suppressed(&self, span: Span, rule: &str) -> bool {
+        self.suppressions.iter().any(|s| s.applies_to(span, rule))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.suppressions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Suppression> {
+        self.suppressions.iter()
+    }
+}
+
+/// Compare rule names, ignoring case, underscores and hyphens.
+pub(crate) fn rule_names_match(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .flat_map(|c| c.to_lowercase())
+            .collect::<String>()
+    };
+
+    normalize(a) == normalize(b)
+}
+
+/// Get the span of each line in the source, excluding the newline character.
+fn line_spans(source: &[char]) -> Vec<Span> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    for (idx, c) in source.iter().enumerate() {
+        if *c == '\n' {
+            lines.push(Span::new(start, idx));
+            start = idx + 1;
+        }
+    }
+
+    lines.push(Span::new(start, source.len()));
+
+    lines
+}
+
+/// Whether a character is part of a non-whitespace token.
+///
+/// Some parsers (like [`crate::parsers::Mask`]) emit whitespace tokens that
+/// span text they otherwise ignore, so those do not count.
+fn is_covered(tokens: &[Token], char_idx: usize) -> bool {
+    let idx = tokens.partition_point(|t| t.span.end <= char_idx);
+
+    tokens.get(idx).is_some_and(|t| {
+        t.span.contains(char_idx) && !t.kind.is_whitespace() && !t.kind.is_paragraph_break()
+    })
+}
+
+fn is_rule_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Find the first directive in a line, returning the index of its first
+/// character.
+fn find_directive(source: &[char], line: Span) -> Option<(usize, Directive)> {
+    let content = line.get_content(source);
+    let prefix: Vec<char> = "harper".chars().collect();
+
+    for start in 0..content.len() {
+        if start > 0 && is_rule_char(content[start - 1]) {
+            continue;
+        }
+
+        let rest = &content[start..];
+
+        if !rest.starts_with(&prefix) || !matches!(rest.get(prefix.len()), Some('-' | ':')) {
+            continue;
+        }
+
+        let rest = &rest[prefix.len() + 1..];
+
+        for (keyword, kind) in DirectiveKind::ALL {
+            let keyword: Vec<char> = keyword.chars().collect();
+
+            if !rest.starts_with(&keyword) {
+                continue;
+            }
+
+            let after = &rest[keyword.len()..];
+
+            if after.first().is_some_and(|c| is_rule_char(*c)) {
+                continue;
+            }
+
+            let rules = parse_rules(after);
+
+            return Some((
+                line.start + start,
+                Directive {
+                    kind,
+                    rules: (!rules.is_empty()).then_some(rules),
+                },
+            ));
+        }
+    }
+
+    None
+}
+
+/// Read the whitespace or comma separated list of rule names that follows a
+/// directive.
+fn parse_rules(mut source: &[char]) -> Vec<String> {
+    let mut rules = Vec::new();
+
+    loop {
+        let skip = source
+            .iter()
+            .position(|c| !(c.is_whitespace() || *c == ','))
+            .unwrap_or(source.len());
+        source = &source[skip..];
+
+        let len = source
+            .iter()
+            .position(|c| !is_rule_char(*c))
+            .unwrap_or(source.len());
+
+        // Comment terminators like `-->` are not rule names.
+        if len == 0 || source[..len].iter().all(|c| *c == '-') {
+            break;
+        }
+
+        rules.push(source[..len].iter().collect());
+        source = &source[len..];
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Suppressions;
+    use crate::parsers::{Parser, PlainEnglish};
+    use crate::Span;
+
+    fn suppressions_for(text: &str) -> (Vec<char>, Suppressions) {
+        let source: Vec<char> = text.chars().collect();
+        let tokens = PlainEnglish.parse(&source);
+        let suppressions = Suppressions::new(&source, &tokens);
+
+        (source, suppressions)
+    }
+
+    fn span_of(source: &[char], needle: &str) -> Span {
+        let text: String = source.iter().collect();
+        let byte_idx = text.find(needle).unwrap();
+        let start = text[..byte_idx].chars().count();
+
+        Span::new_with_len(start, needle.chars().count())
+    }
+
+    #[test]
+    fn ignores_next_line() {
+        let (source, sup) = suppressions_for("harper-ignore-next-line\nthis is bad\nthis is fine");
+
+        assert!(sup.is_suppressed(span_of(&source, "bad"), "spell_check"));
+        assert!(!sup.is_suppressed(span_of(&source, "fine"), "spell_check"));
+    }
+
+    #[test]
+    fn ignores_same_line() {
+        let (source, sup) = suppressions_for("this is bad harper-ignore-line\nthis is fine");
+
+        assert!(sup.is_suppressed(span_of(&source, "bad"), "AnA"));
+        assert!(!sup.is_suppressed(span_of(&source, "fine"), "AnA"));
+    }
+
+    #[test]
+    fn disables_named_rules() {
+        let (source, sup) = suppressions_for(
+            "harper-disable an_a, spell_check\nthis is bad\nharper-enable\nthis is fine",
+        );
+
+        let bad = span_of(&source, "bad");
+
+        assert!(sup.is_suppressed(bad, "AnA"));
+        assert!(sup.is_suppressed(bad, "SpellCheck"));
+        assert!(!sup.is_suppressed(bad, "Spaces"));
+        assert!(!sup.is_suppressed(span_of(&source, "fine"), "AnA"));
+    }
+
+    #[test]
+    fn partially_enables_rules() {
+        let (source, sup) =
+            suppressions_for("harper-disable an_a spell_check\nthis\nharper-enable an_a\nthat");
+
+        let that = span_of(&source, "that");
+
+        assert!(sup.is_suppressed(span_of(&source, "this"), "an_a"));
+        assert!(!sup.is_suppressed(that, "an_a"));
+        assert!(sup.is_suppressed(that, "spell_check"));
+    }
+
+    #[test]
+    fn disable_runs_to_end() {
+        let (source, sup) = suppressions_for("fine\nharper-disable\nthis is bad\nso is this");
+
+        assert!(sup.is_suppressed(span_of(&source, "so"), "spell_check"));
+        assert!(!sup.is_suppressed(span_of(&source, "fine"), "spell_check"));
+    }
+
+    #[test]
+    fn requires_word_boundary() {
+        let (_, sup) = suppressions_for("notharper-disable\nthis is bad");
+
+        assert!(sup.is_empty());
+    }
+
+    #[test]
+    fn ignores_html_comment_terminator() {
+        let (source, sup) =
+            suppressions_for("<!-- harper-ignore-next-line -->\nthis is bad\nthis is fine");
+
+        assert!(sup.is_suppressed(span_of(&source, "bad"), "spell_check"));
+        assert!(!sup.is_suppressed(span_of(&source, "fine"), "spell_check"));
+    }
+}
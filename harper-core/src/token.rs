@@ -13,11 +13,32 @@ use crate::{Quote, WordMetadata};
 pub struct Token {
     pub span: Span,
     pub kind: TokenKind,
+    /// Where the token sits in the structure of the document.
+    /// Parsers for plain text leave this empty.
+    #[serde(default)]
+    pub context: TokenContext,
 }
 
 impl Token {
     pub fn new(span: Span, kind: TokenKind) -> Self {
-        Self { span, kind }
+        Self {
+            span,
+            kind,
+            context: TokenContext::default(),
+        }
+    }
+
+    /// Checks whether the token ends a sentence, either because it is a
+    /// sentence terminator or because it was marked as ending one.
+    /// See [`TokenContext::sentence_end`].
+    pub fn is_sentence_terminator(&self) -> bool {
+        self.kind.is_sentence_terminator() || self.context.sentence_end
+    }
+
+    /// Checks whether the token ends a chunk. See
+    /// [`TokenKind::is_chunk_terminator`].
+    pub fn is_chunk_terminator(&self) -> bool {
+        self.kind.is_chunk_terminator() || self.context.sentence_end
     }
 
     /// Convert to an allocated [`FatToken`].
@@ -39,6 +60,35 @@ pub struct FatToken {
     pub kind: TokenKind,
 }
 
+/// The structural elements of a document that contain a [`Token`], like the
+/// headings and list items of a Markdown file.
+///
+/// Linters can use this to treat text differently depending on where it
+/// appears. For example, headings don't usually end with a period.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, Hash, Eq, PartialEq)]
+#[serde(default)]
+pub struct TokenContext {
+    /// The level of the heading that contains the token, from 1 to 6.
+    pub heading_level: Option<u8>,
+    pub list_item: bool,
+    pub block_quote: bool,
+    pub table_cell: bool,
+    /// Whether the token is part of the visible text of a link.
+    pub link_text: bool,
+    /// Whether the token is italicized or emboldened.
+    pub emphasis: bool,
+    /// Whether the token ends its sentence without being a sentence
+    /// terminator, like the "U.S." in "I live in the U.S. It is big.", whose
+    /// period also ends the sentence.
+    pub sentence_end: bool,
+}
+
+impl TokenContext {
+    pub fn is_heading(&self) -> bool {
+        self.heading_level.is_some()
+    }
+}
+
 #[derive(
     Debug, Is, Clone, Copy, Serialize, Deserialize, Default, PartialOrd, Hash, Eq, PartialEq,
 )]
@@ -70,6 +120,14 @@ impl TokenKind {
         matches!(self, TokenKind::Punctuation(Punctuation::CloseSquare))
     }
 
+    pub fn is_open_round(&self) -> bool {
+        matches!(self, TokenKind::Punctuation(Punctuation::OpenRound))
+    }
+
+    pub fn is_close_round(&self) -> bool {
+        matches!(self, TokenKind::Punctuation(Punctuation::CloseRound))
+    }
+
     pub fn is_pipe(&self) -> bool {
         matches!(self, TokenKind::Punctuation(Punctuation::Pipe))
     }
@@ -97,7 +155,7 @@ impl TokenKind {
         )
     }
 
-    fn is_chunk_terminator(&self) -> bool {
+    pub fn is_chunk_terminator(&self) -> bool {
         if self.is_sentence_terminator() {
             return true;
         }
@@ -113,7 +171,7 @@ impl TokenKind {
         }
     }
 
-    fn is_sentence_terminator(&self) -> bool {
+    pub fn is_sentence_terminator(&self) -> bool {
         match self {
             TokenKind::Punctuation(punct) => [
                 Punctuation::Period,
@@ -287,6 +345,10 @@ impl TokenKind {
         matches!(self, TokenKind::Punctuation(Punctuation::Period))
     }
 
+    pub fn is_hyphen(&self) -> bool {
+        matches!(self, TokenKind::Punctuation(Punctuation::Hyphen))
+    }
+
     pub fn is_at(&self) -> bool {
         matches!(self, TokenKind::Punctuation(Punctuation::At))
     }
@@ -335,6 +397,10 @@ impl TokenKind {
         matches!(self, TokenKind::Punctuation(Punctuation::Comma))
     }
 
+    pub fn is_colon(&self) -> bool {
+        matches!(self, TokenKind::Punctuation(Punctuation::Colon))
+    }
+
     /// Checks whether the token is whitespace.
     pub fn is_whitespace(&self) -> bool {
         matches!(self, TokenKind::Space(_) | TokenKind::Newline(_))
@@ -359,23 +425,28 @@ macro_rules! create_decl_for {
 
 macro_rules! create_fns_for {
     ($thing:ident) => {
+        paste! {
+            create_fns_for!($thing, |v: &Token| v.kind.[<is_ $thing>]());
+        }
+    };
+    ($thing:ident, $predicate:expr) => {
         paste! {
             fn [< first_ $thing >](&self) -> Option<Token> {
-                self.iter().find(|v| v.kind.[<is_ $thing>]()).copied()
+                self.iter().find(|v| $predicate(v)).copied()
             }
 
             fn [< last_ $thing >](&self) -> Option<Token> {
-                self.iter().rev().find(|v| v.kind.[<is_ $thing>]()).copied()
+                self.iter().rev().find(|v| $predicate(v)).copied()
             }
 
             fn [< last_ $thing _index >](&self) -> Option<usize> {
-                self.iter().rev().position(|v| v.kind.[<is_ $thing>]()).map(|i| self.len() - i - 1)
+                self.iter().rev().position(|v| $predicate(v)).map(|i| self.len() - i - 1)
             }
 
             fn [<iter_ $thing _indices>](&self) -> impl Iterator<Item = usize> + '_ {
                 self.iter()
                     .enumerate()
-                    .filter(|(_, t)| t.kind.[<is_ $thing>]())
+                    .filter(|(_, t)| $predicate(t))
                     .map(|(i, _)| i)
             }
 
@@ -428,6 +499,29 @@ pub trait TokenStringExt {
     /// Get an iterator over token slices that represent the individual
     /// sentences in a document.
     fn iter_sentences(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over the headings in a document.
+    ///
+    /// Unlike [`Self::iter_paragraphs`], the slices returned by this and the
+    /// other iterators over structural elements don't include the whitespace
+    /// or paragraph breaks around them.
+    fn iter_headings(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over the items of the lists in a document.
+    /// Items that contain several paragraphs are split into them.
+    fn iter_list_items(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over the paragraphs inside of block quotes.
+    fn iter_block_quotes(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over the cells of the tables in a document.
+    fn iter_table_cells(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over the visible text of each link.
+    fn iter_link_texts(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
+
+    /// Get an iterator over runs of italicized or emboldened text.
+    fn iter_emphases(&self) -> impl Iterator<Item = &'_ [Token]> + '_;
 }
 
 impl TokenStringExt for [Token] {
@@ -441,9 +535,9 @@ impl TokenStringExt for [Token] {
     create_fns_for!(punctuation);
     create_fns_for!(ellipsis);
     create_fns_for!(unlintable);
-    create_fns_for!(sentence_terminator);
+    create_fns_for!(sentence_terminator, Token::is_sentence_terminator);
     create_fns_for!(paragraph_break);
-    create_fns_for!(chunk_terminator);
+    create_fns_for!(chunk_terminator, Token::is_chunk_terminator);
     create_fns_for!(likely_homograph);
 
     fn first_non_whitespace(&self) -> Option<Token> {
@@ -554,6 +648,60 @@ impl TokenStringExt for [Token] {
 
         first_sentence.into_iter().chain(rest).chain(last_sentence)
     }
+
+    fn iter_headings(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        paragraphs_in(self, TokenContext::is_heading)
+    }
+
+    fn iter_list_items(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        paragraphs_in(self, |context| context.list_item)
+    }
+
+    fn iter_block_quotes(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        paragraphs_in(self, |context| context.block_quote)
+    }
+
+    fn iter_table_cells(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        paragraphs_in(self, |context| context.table_cell)
+    }
+
+    fn iter_link_texts(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        runs_in(self, |context| context.link_text)
+    }
+
+    fn iter_emphases(&self) -> impl Iterator<Item = &'_ [Token]> + '_ {
+        runs_in(self, |context| context.emphasis)
+    }
+}
+
+/// Get the paragraphs whose content is inside the structural element
+/// described by `predicate`, without the whitespace around them.
+fn paragraphs_in(
+    tokens: &[Token],
+    predicate: fn(&TokenContext) -> bool,
+) -> impl Iterator<Item = &'_ [Token]> + '_ {
+    tokens
+        .iter_paragraphs()
+        .filter_map(|paragraph| {
+            let is_blank = |t: &Token| t.kind.is_whitespace() || t.kind.is_paragraph_break();
+
+            let start = paragraph.iter().position(|t| !is_blank(t))?;
+            let end = paragraph.iter().rposition(|t| !is_blank(t))?;
+
+            Some(&paragraph[start..=end])
+        })
+        .filter(move |content| predicate(&content[0].context))
+}
+
+/// Get the longest runs of tokens that are inside the inline element
+/// described by `predicate`.
+fn runs_in(
+    tokens: &[Token],
+    predicate: fn(&TokenContext) -> bool,
+) -> impl Iterator<Item = &'_ [Token]> + '_ {
+    tokens
+        .chunk_by(move |a, b| predicate(&a.context) == predicate(&b.context))
+        .filter(move |run| predicate(&run[0].context))
 }
 
 #[cfg(test)]
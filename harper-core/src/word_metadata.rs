@@ -1,7 +1,12 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 use is_macro::Is;
 use paste::paste;
 use serde::{Deserialize, Serialize};
 
+use crate::PosTag;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Hash)]
 pub struct WordMetadata {
     pub noun: Option<NounData>,
@@ -19,6 +24,13 @@ pub struct WordMetadata {
     /// Whether the word is considered especially common.
     #[serde(default = "default_false")]
     pub common: bool,
+    /// The dialects of English the word is spelled correctly in.
+    /// If [`None`], the word is assumed to belong to all of them.
+    pub dialects: Option<DialectFlags>,
+    /// The part of speech the word plays in its sentence, if it has been
+    /// tagged. See [`Self::set_pos_tag`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos_tag: Option<PosTag>,
 }
 
 /// Needed for `serde`
@@ -98,22 +110,73 @@ impl WordMetadata {
             article: self.article || other.article,
             preposition: self.preposition || other.preposition,
             common: self.common || other.common,
+            dialects: merge!(self.dialects, other.dialects),
+            pos_tag: self.pos_tag.or(other.pos_tag),
         }
     }
 
     generate_metadata_queries!(
         noun has proper, plural, possessive, pronoun.
-        verb has linking.
+        verb has linking, third_person_singular, past_participle.
         conjunction has.
         adjective has.
         adverb has
     );
 
+    /// Checks whether the word is spelled correctly in a given dialect.
+    pub fn is_in_dialect(&self, dialect: Dialect) -> bool {
+        self.dialects.is_none_or(|flags| flags.contains(dialect))
+    }
+
     /// Checks whether a word is _definitely_ a swear.
     pub fn is_swear(&self) -> bool {
         matches!(self.swear, Some(true))
     }
 
+    /// Record the part of speech the word plays in its sentence, as assigned
+    /// by a [`PosTagger`](crate::PosTagger).
+    ///
+    /// The tag is only kept if the dictionary already lists the word in the
+    /// matching category, so the tagger can only choose between the readings
+    /// a word is known to have. The categories themselves are left alone.
+    pub fn set_pos_tag(&mut self, tag: PosTag) {
+        let known = match tag {
+            PosTag::Noun | PosTag::Pronoun => self.is_noun(),
+            PosTag::Verb => self.is_verb(),
+            PosTag::Adjective => self.is_adjective(),
+            PosTag::Adverb => self.is_adverb(),
+            PosTag::Conjunction => self.is_conjunction(),
+            PosTag::Determiner => self.article,
+            PosTag::Preposition => self.preposition,
+            PosTag::Number | PosTag::Punctuation | PosTag::Other => false,
+        };
+
+        if known {
+            self.pos_tag = Some(tag);
+        }
+    }
+
+    /// Produce a copy of `self` with every category removed except the one
+    /// matching [`Self::pos_tag`]. Untagged words are returned unchanged.
+    pub fn narrowed(&self) -> Self {
+        let Some(tag) = self.pos_tag else {
+            return *self;
+        };
+
+        let is_noun = matches!(tag, PosTag::Noun | PosTag::Pronoun);
+
+        Self {
+            noun: self.noun.filter(|_| is_noun),
+            verb: self.verb.filter(|_| tag == PosTag::Verb),
+            adjective: self.adjective.filter(|_| tag == PosTag::Adjective),
+            adverb: self.adverb.filter(|_| tag == PosTag::Adverb),
+            conjunction: self.conjunction.filter(|_| tag == PosTag::Conjunction),
+            article: self.article && tag == PosTag::Determiner,
+            preposition: self.preposition && tag == PosTag::Preposition,
+            ..*self
+        }
+    }
+
     /// Same thing as [`Self::or`], except in-place rather than a copy.
     pub fn append(&mut self, other: &Self) -> &mut Self {
         *self = self.or(other);
@@ -121,6 +184,102 @@ impl WordMetadata {
     }
 }
 
+/// A regional variety of English, which determines how words like "color"
+/// and "organize" are spelled.
+///
+/// Dialects are deserialized through [`FromStr`], so configuration accepts
+/// the same names as the command line, like `british` or `en-GB`.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash,
+)]
+#[serde(try_from = "String")]
+pub enum Dialect {
+    #[default]
+    American,
+    British,
+    Canadian,
+    Australian,
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::American => "American",
+            Self::British => "British",
+            Self::Canadian => "Canadian",
+            Self::Australian => "Australian",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown dialect `{0}`. Expected one of American, British, Canadian or Australian.")]
+pub struct UnknownDialectError(String);
+
+impl FromStr for Dialect {
+    type Err = UnknownDialectError;
+
+    /// Parse a dialect from its name or country code, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "american" | "us" | "en-us" => Ok(Self::American),
+            "british" | "uk" | "gb" | "en-gb" => Ok(Self::British),
+            "canadian" | "ca" | "en-ca" => Ok(Self::Canadian),
+            "australian" | "au" | "en-au" => Ok(Self::Australian),
+            _ => Err(UnknownDialectError(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Dialect {
+    type Error = UnknownDialectError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// A set of [`Dialect`]s.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Hash)]
+#[serde(default)]
+pub struct DialectFlags {
+    pub american: bool,
+    pub british: bool,
+    pub canadian: bool,
+    pub australian: bool,
+}
+
+impl DialectFlags {
+    /// The set of every dialect.
+    pub const ALL: Self = Self {
+        american: true,
+        british: true,
+        canadian: true,
+        australian: true,
+    };
+
+    pub fn contains(&self, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::American => self.american,
+            Dialect::British => self.british,
+            Dialect::Canadian => self.canadian,
+            Dialect::Australian => self.australian,
+        }
+    }
+
+    /// Produce the union of `self` and `other`.
+    pub fn or(&self, other: &Self) -> Self {
+        Self {
+            american: self.american || other.american,
+            british: self.british || other.british,
+            canadian: self.canadian || other.canadian,
+            australian: self.australian || other.australian,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash)]
 pub enum Tense {
     Past,
@@ -132,6 +291,12 @@ pub enum Tense {
 pub struct VerbData {
     pub is_linking: Option<bool>,
     pub tense: Option<Tense>,
+    /// Whether this is the form used with third-person singular subjects,
+    /// like "shows" in "she shows", rather than "show" in "they show".
+    pub is_third_person_singular: Option<bool>,
+    /// Whether this is a past participle, like "approved" or "eaten", which
+    /// follows "to be" in the passive voice.
+    pub is_past_participle: Option<bool>,
 }
 
 impl VerbData {
@@ -140,6 +305,10 @@ impl VerbData {
         Self {
             is_linking: self.is_linking.or(other.is_linking),
             tense: self.tense.or(other.tense),
+            is_third_person_singular: self
+                .is_third_person_singular
+                .or(other.is_third_person_singular),
+            is_past_participle: self.is_past_participle.or(other.is_past_participle),
         }
     }
 }
@@ -19,20 +19,23 @@ use tower_lsp::lsp_types::{
     CodeActionOrCommand, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
     Command, ConfigurationItem, Diagnostic, DidChangeConfigurationParams,
     DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-    DidSaveTextDocumentParams, ExecuteCommandOptions, ExecuteCommandParams, InitializeParams,
-    InitializeResult, InitializedParams, MessageType, PublishDiagnosticsParams, Range,
-    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    DidSaveTextDocumentParams, ExecuteCommandOptions, ExecuteCommandParams, Hover, HoverContents,
+    HoverParams, HoverProviderCapability, InitializeParams, InitializeResult, InitializedParams,
+    MarkupContent, MarkupKind, MessageType, PublishDiagnosticsParams, Range, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
     TextDocumentSyncSaveOptions, Url,
 };
 use tower_lsp::{Client, LanguageServer};
 use tracing::{error, info};
 
 use crate::config::Config;
-use crate::diagnostics::{lint_to_code_actions, lints_to_diagnostics};
+use crate::diagnostics::{
+    lint_to_code_actions, lint_to_hover_markdown, lints_to_diagnostics, lints_to_fix_all_action,
+};
 use crate::dictionary_io::{load_dict, save_dict};
 use crate::document_state::DocumentState;
 use crate::git_commit_parser::GitCommitParser;
-use crate::pos_conv::range_to_span;
+use crate::pos_conv::{range_to_span, span_to_range};
 
 pub struct Backend {
     client: Client,
@@ -164,7 +167,7 @@ impl Backend {
         let dict = Arc::new(self.generate_file_dictionary(url).await?);
 
         let doc_state = doc_lock.entry(url.clone()).or_insert(DocumentState {
-            linter: LintGroup::new(config_lock.lint_config, dict.clone()),
+            linter: LintGroup::new(config_lock.lint_config.clone(), dict.clone()),
             language_id: language_id.map(|v| v.to_string()),
             dict: dict.clone(),
             ..Default::default()
@@ -172,7 +175,7 @@ impl Backend {
 
         if doc_state.dict != dict {
             doc_state.dict = dict.clone();
-            doc_state.linter = LintGroup::new(config_lock.lint_config, dict.clone());
+            doc_state.linter = LintGroup::new(config_lock.lint_config.clone(), dict.clone());
         }
 
         let Some(language_id) = &doc_state.language_id else {
@@ -194,7 +197,8 @@ impl Backend {
                         merged.add_dictionary(new_dict);
                         let merged = Arc::new(merged);
 
-                        doc_state.linter = LintGroup::new(config_lock.lint_config, merged.clone());
+                        doc_state.linter =
+                            LintGroup::new(config_lock.lint_config.clone(), merged.clone());
                         doc_state.dict = merged.clone();
                     }
                     Some(Box::new(CollapseIdentifiers::new(
@@ -250,6 +254,8 @@ impl Backend {
         // Find lints whole span overlaps with range
         let span = range_to_span(source_chars, range).with_len(1);
 
+        let fix_all = lints_to_fix_all_action(&lints, url, source_chars);
+
         let mut actions: Vec<CodeActionOrCommand> = lints
             .into_iter()
             .filter(|lint| lint.span.overlaps_with(span))
@@ -271,22 +277,51 @@ impl Backend {
             )))
         }
 
+        if let Some(fix_all) = fix_all {
+            actions.push(CodeActionOrCommand::CodeAction(fix_all));
+        }
+
         Ok(actions)
     }
 
+    async fn generate_hover(&self, url: &Url, range: Range) -> Option<Hover> {
+        let mut doc_states = self.doc_state.lock().await;
+        let doc_state = doc_states.get_mut(url)?;
+
+        let source_chars = doc_state.document.get_full_content();
+        let span = range_to_span(source_chars, range).with_len(1);
+
+        let lint = doc_state
+            .lints
+            .iter()
+            .filter(|lint| lint.span.overlaps_with(span))
+            .min_by_key(|lint| lint.priority)?;
+
+        let metadata = doc_state.linter.get_metadata(&lint.rule_id);
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: lint_to_hover_markdown(lint, metadata),
+            }),
+            range: Some(span_to_range(source_chars, lint.span)),
+        })
+    }
+
     async fn generate_diagnostics(&self, url: &Url) -> Vec<Diagnostic> {
         let mut doc_states = self.doc_state.lock().await;
         let Some(doc_state) = doc_states.get_mut(url) else {
             return Vec::new();
         };
 
-        let lints = doc_state.linter.lint(&doc_state.document);
+        doc_state.lints = doc_state.linter.lint(&doc_state.document);
         let config = self.config.read().await;
 
         lints_to_diagnostics(
             doc_state.document.get_full_content(),
-            &lints,
+            &doc_state.lints,
             config.diagnostic_severity,
+            |rule_id| doc_state.linter.get_metadata(rule_id),
         )
     }
 
@@ -344,6 +379,7 @@ impl LanguageServer for Backend {
             server_info: None,
             capabilities: ServerCapabilities {
                 code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
                 execute_command_provider: Some(ExecuteCommandOptions {
                     commands: vec![
                         "HarperAddToUserDict".to_owned(),
@@ -483,7 +519,7 @@ impl LanguageServer for Backend {
             let config_lock = self.config.read().await;
 
             for doc in doc_lock.values_mut() {
-                doc.linter = LintGroup::new(config_lock.lint_config, doc.dict.clone());
+                doc.linter = LintGroup::new(config_lock.lint_config.clone(), doc.dict.clone());
             }
 
             doc_lock.keys().cloned().collect()
@@ -503,6 +539,17 @@ impl LanguageServer for Backend {
         Ok(Some(actions))
     }
 
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params.position;
+
+        Ok(self
+            .generate_hover(
+                &params.text_document_position_params.text_document.uri,
+                Range::new(position, position),
+            )
+            .await)
+    }
+
     async fn shutdown(&self) -> Result<()> {
         let doc_states = self.doc_state.lock().await;
 
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use dirs::{config_dir, data_local_dir};
-use harper_core::linting::LintGroupConfig;
+use harper_core::linting::{GlossaryEntry, LintGroupConfig, MatcherRule};
 use resolve_path::PathResolveExt;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -67,6 +67,10 @@ pub struct Config {
     pub user_dict_path: PathBuf,
     pub file_dict_path: PathBuf,
     pub lint_config: LintGroupConfig,
+    /// A JSON file containing additional rules for the `Matcher` linter.
+    pub matcher_rules_path: Option<PathBuf>,
+    /// A JSON file containing the project glossary for the `Terminology` linter.
+    pub glossary_path: Option<PathBuf>,
     pub diagnostic_severity: DiagnosticSeverity,
     pub code_action_config: CodeActionConfig,
     pub isolate_english: bool,
@@ -106,6 +110,61 @@ impl Config {
             base.lint_config = serde_json::from_value(v.clone())?;
         }
 
+        if let Some(v) = value.get("dialect") {
+            if let Value::String(dialect) = v {
+                base.lint_config.dialect = Some(dialect.parse()?);
+            } else {
+                return Err(anyhow::format_err!("dialect must be a string."));
+            }
+        }
+
+        if let Some(v) = value.get("preset") {
+            if let Value::String(preset) = v {
+                base.lint_config.preset = Some(preset.parse()?);
+            } else {
+                return Err(anyhow::format_err!("preset must be a string."));
+            }
+        }
+
+        if let Some(v) = value.get("matcherRulesPath") {
+            if let Value::String(path) = v {
+                base.matcher_rules_path = Some(path.try_resolve()?.to_path_buf());
+            } else {
+                return Err(anyhow::format_err!("matcherRules path must be a string."));
+            }
+        }
+
+        if let Some(path) = &base.matcher_rules_path {
+            let rules: Vec<MatcherRule> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+            base.lint_config
+                .matcher
+                .get_or_insert_with(Default::default)
+                .options_mut()
+                .rules
+                .extend(rules);
+        }
+
+        if let Some(v) = value.get("glossaryPath") {
+            if let Value::String(path) = v {
+                base.glossary_path = Some(path.try_resolve()?.to_path_buf());
+            } else {
+                return Err(anyhow::format_err!("glossary path must be a string."));
+            }
+        }
+
+        if let Some(path) = &base.glossary_path {
+            let glossary: Vec<GlossaryEntry> =
+                serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+            base.lint_config
+                .terminology
+                .get_or_insert_with(Default::default)
+                .options_mut()
+                .glossary
+                .extend(glossary);
+        }
+
         if let Some(v) = value.get("diagnosticSeverity") {
             base.diagnostic_severity = serde_json::from_value(v.clone())?;
         }
@@ -136,6 +195,8 @@ impl Default for Config {
                 .unwrap()
                 .join("harper-ls/file_dictionaries/"),
             lint_config: LintGroupConfig::default(),
+            matcher_rules_path: None,
+            glossary_path: None,
             diagnostic_severity: DiagnosticSeverity::Hint,
             code_action_config: CodeActionConfig::default(),
             isolate_english: false,
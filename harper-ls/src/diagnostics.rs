@@ -1,22 +1,25 @@
 use std::collections::HashMap;
 
-use harper_core::linting::{Lint, Suggestion};
+use harper_core::linting::{Autofixer, FixPolicy, Lint, LinterMetadata};
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, TextEdit, Url,
-    WorkspaceEdit,
+    CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, NumberOrString, TextEdit,
+    Url, WorkspaceEdit,
 };
 
 use crate::config::{CodeActionConfig, DiagnosticSeverity};
 use crate::pos_conv::span_to_range;
 
-pub fn lints_to_diagnostics(
+/// Convert lints to diagnostics, describing the rule behind each with the
+/// metadata `get_metadata` finds for it.
+pub fn lints_to_diagnostics<'a>(
     source: &[char],
     lints: &[Lint],
     severity: DiagnosticSeverity,
+    get_metadata: impl Fn(&str) -> Option<&'a LinterMetadata>,
 ) -> Vec<Diagnostic> {
     lints
         .iter()
-        .map(|lint| lint_to_diagnostic(lint, source, severity))
+        .map(|lint| lint_to_diagnostic(lint, get_metadata(&lint.rule_id), source, severity))
         .collect()
 }
 
@@ -32,25 +35,21 @@ pub fn lint_to_code_actions<'a>(
         lint.suggestions
             .iter()
             .flat_map(|suggestion| {
-                let range = span_to_range(source, lint.span);
-
-                let replace_string = match suggestion {
-                    Suggestion::ReplaceWith(with) => with.iter().collect(),
-                    Suggestion::Remove => "".to_string(),
-                };
+                let edits = suggestion
+                    .replacements(lint.span)
+                    .into_iter()
+                    .map(|replacement| TextEdit {
+                        range: span_to_range(source, replacement.span),
+                        new_text: replacement.text.iter().collect(),
+                    })
+                    .collect();
 
                 Some(CodeAction {
                     title: suggestion.to_string(),
                     kind: Some(CodeActionKind::QUICKFIX),
                     diagnostics: None,
                     edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            url.clone(),
-                            vec![TextEdit {
-                                range,
-                                new_text: replace_string,
-                            }],
-                        )])),
+                        changes: Some(HashMap::from([(url.clone(), edits)])),
                         document_changes: None,
                         change_annotations: None,
                     }),
@@ -86,16 +85,94 @@ pub fn lint_to_code_actions<'a>(
     results
 }
 
-fn lint_to_diagnostic(lint: &Lint, source: &[char], severity: DiagnosticSeverity) -> Diagnostic {
+/// Create a code action that applies every unambiguous fix in the document at
+/// once, if there are any.
+pub fn lints_to_fix_all_action(lints: &[Lint], url: &Url, source: &[char]) -> Option<CodeAction> {
+    let edits = Autofixer::new(FixPolicy::OnlyUnambiguous, 1).plan(lints);
+
+    if edits.is_empty() {
+        return None;
+    }
+
+    let text_edits = edits
+        .into_iter()
+        .map(|edit| TextEdit {
+            range: span_to_range(source, edit.span),
+            new_text: edit.replacement.iter().collect(),
+        })
+        .collect();
+
+    Some(CodeAction {
+        title: "Fix all problems".to_string(),
+        kind: Some(CodeActionKind::SOURCE_FIX_ALL),
+        diagnostics: None,
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(url.clone(), text_edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Explain a lint (and the rule that produced it) in Markdown.
+pub fn lint_to_hover_markdown(lint: &Lint, metadata: Option<&LinterMetadata>) -> String {
+    let mut markdown = String::new();
+
+    if let Some(metadata) = metadata {
+        markdown.push_str(&format!(
+            "**{}** (`{}`)\n\n",
+            metadata.description.name, metadata.rule_id
+        ));
+    }
+
+    markdown.push_str(&lint.message);
+
+    if let Some(metadata) = metadata {
+        let description = &metadata.description;
+
+        for paragraph in [&description.summary, &description.long_description] {
+            if !paragraph.is_empty() {
+                markdown.push_str("\n\n");
+                markdown.push_str(paragraph);
+            }
+        }
+    }
+
+    markdown
+}
+
+fn lint_to_diagnostic(
+    lint: &Lint,
+    metadata: Option<&LinterMetadata>,
+    source: &[char],
+    severity: DiagnosticSeverity,
+) -> Diagnostic {
     let range = span_to_range(source, lint.span);
 
+    let mut message = lint.message.clone();
+
+    if let Some(metadata) = metadata {
+        let description = &metadata.description;
+
+        if !description.summary.is_empty() {
+            message.push_str(&format!(
+                "\n\n{}: {}",
+                description.name, description.summary
+            ));
+        }
+    }
+
     Diagnostic {
         range,
         severity: Some(severity.to_lsp()),
-        code: None,
+        code: (!lint.rule_id.is_empty()).then(|| NumberOrString::String(lint.rule_id.clone())),
         code_description: None,
         source: Some("Harper".to_string()),
-        message: lint.message.clone(),
+        message,
         related_information: None,
         tags: None,
         data: None,
@@ -1,4 +1,4 @@
-use harper_core::linting::LintGroup;
+use harper_core::linting::{Lint, LintGroup};
 use harper_core::{Document, FullDictionary, Lrc, MergedDictionary};
 
 #[derive(Default)]
@@ -7,5 +7,7 @@ pub struct DocumentState {
     pub ident_dict: Lrc<FullDictionary>,
     pub dict: Lrc<MergedDictionary>,
     pub linter: LintGroup<Lrc<MergedDictionary>>,
+    /// The lints from the last time diagnostics were published.
+    pub lints: Vec<Lint>,
     pub language_id: Option<String>,
 }
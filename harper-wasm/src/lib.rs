@@ -4,13 +4,14 @@ use std::convert::Into;
 use std::sync::Arc;
 
 use harper_core::language_detection::is_doc_likely_english;
-use harper_core::linting::{LintGroup, LintGroupConfig, Linter as _};
+use harper_core::linting::{LintGroup, LintGroupConfig, Linter as _, StylePreset};
 use harper_core::parsers::{IsolateEnglish, PlainEnglish};
+use harper_core::readability::ReadabilityStats;
 use harper_core::{remove_overlaps, Document, FstDictionary, FullDictionary, Lrc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
-use regex::Regex;
 
 /// Setup the WebAssembly module's logging.
 ///
@@ -43,7 +44,6 @@ make_serialize_fns_for!(Suggestion);
 make_serialize_fns_for!(Lint);
 make_serialize_fns_for!(Span);
 
-
 #[wasm_bindgen]
 pub struct Linter {
     lint_group: LintGroup<Arc<FstDictionary>>,
@@ -103,6 +103,60 @@ impl Linter {
         Ok(())
     }
 
+    /// Get the name of the style preset the lint config follows, if any.
+    pub fn get_style_preset(&self) -> Option<String> {
+        self.lint_group
+            .config
+            .preset
+            .map(|preset| preset.to_string())
+    }
+
+    /// Follow a style preset (e.g. `google-dev-docs`), or none at all.
+    /// Any other settings in the lint config take precedence over the preset's.
+    pub fn set_style_preset(&mut self, preset: Option<String>) -> Result<(), String> {
+        self.lint_group.config.preset = preset
+            .map(|preset| preset.parse::<StylePreset>())
+            .transpose()
+            .map_err(|err| err.to_string())?;
+        Ok(())
+    }
+
+    /// Get the names of the available style presets.
+    pub fn get_style_presets(&self) -> Vec<String> {
+        StylePreset::ALL
+            .iter()
+            .map(|preset| preset.to_string())
+            .collect()
+    }
+
+    /// Get the name, description, examples and default state of every available rule.
+    pub fn get_lint_descriptions(&self) -> JsValue {
+        // Important for downstream JSON serialization
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+        let descriptions: Vec<_> = self.lint_group.metadata().collect();
+        descriptions.serialize(&serializer).unwrap()
+    }
+
+    pub fn get_lint_descriptions_as_json(&self) -> String {
+        let descriptions: Vec<_> = self.lint_group.metadata().collect();
+        serde_json::to_string(&descriptions).unwrap()
+    }
+
+    /// Get readability metrics, like the Flesch reading ease, for the provided text.
+    pub fn get_readability_stats(&self, text: String) -> JsValue {
+        // Important for downstream JSON serialization
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+        self.readability_report(&text)
+            .serialize(&serializer)
+            .unwrap()
+    }
+
+    pub fn get_readability_stats_as_json(&self, text: String) -> String {
+        serde_json::to_string(&self.readability_report(&text)).unwrap()
+    }
+
     pub fn clean_mdx_content(&mut self, mdx: &str) -> String {
         // Regex to match HTML tags and preserve attribute values.
         let tag_regex = Regex::new(r#"<(/?[\w\-]+)([^>]*)>"#).unwrap();
@@ -111,7 +165,7 @@ impl Linter {
         let image_tag_regex = Regex::new(r#"\!\[([^\]]+)\]\([^\)]+\)"#).unwrap();
         // Regex for Markdown links.
         let link_tag_regex = Regex::new(r#"\[([^\]]+)\]\([^\)]+\)"#).unwrap();
-    
+
         // Regex for URLs (simple matching).
         let url_regex = Regex::new(r#"(https?://[^\s]+)"#).unwrap();
         // Regex for email addresses.
@@ -127,7 +181,7 @@ impl Linter {
         let dash_regex = Regex::new(r#"-{2,}"#).unwrap();
         // Regex to match non-English characters.
         let non_english_words_regex = Regex::new(r#"[^\x00-\x7F]+"#).unwrap();
-    
+
         // Step 1: Replace Markdown image tags, preserving their alt text but placing a space before it.
         let cleaned = image_tag_regex.replace_all(mdx, |caps: &regex::Captures| {
             let alt_text = &caps[1];
@@ -137,7 +191,7 @@ impl Linter {
                 " ".repeat(caps[0].chars().count() - alt_text.chars().count() - 2)
             )
         });
-    
+
         // Step 2: Replace Markdown link tags, preserving their link text but placing a space before it.
         let cleaned = link_tag_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             let link_text = &caps[1];
@@ -147,20 +201,20 @@ impl Linter {
                 " ".repeat(caps[0].chars().count() - link_text.chars().count() - 1)
             )
         });
-    
+
         // Step 3: Replace code blocks with spaces.
         let cleaned = code_block_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             " ".repeat(caps[0].chars().count())
         });
-    
+
         // Step 4: Clean up HTML tags while preserving attribute values.
         let cleaned = tag_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             let tag_name = &caps[1];
             let attributes = &caps[2];
-    
+
             // Replace the tag name with spaces.
             let mut result = " ".repeat(tag_name.chars().count() + 1);
-    
+
             // Preserve the attribute values while replacing attribute names with spaces.
             let cleaned_attributes =
                 attr_regex.replace_all(attributes, |attr_caps: &regex::Captures| {
@@ -170,22 +224,22 @@ impl Linter {
                         &attr_caps[1]
                     )
                 });
-    
+
             result.push_str(&cleaned_attributes);
             result.push_str(" ");
             result
         });
-    
+
         // Step 5: Replace URLs with spaces.
         let cleaned = url_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             " ".repeat(caps[0].chars().count())
         });
-    
+
         // Step 6: Replace email addresses with spaces.
         let cleaned = email_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             " ".repeat(caps[0].chars().count())
         });
-    
+
         // Step 7: Replace properly closed inline code snippets with spaces.
         let cleaned = inline_code_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             let content = &caps[1];
@@ -196,34 +250,37 @@ impl Linter {
                 caps[0].to_string() // Leave it unchanged if it doesn't meet the criteria.
             }
         });
-    
+
         // Step 8: Replace emojis with spaces, accounting for their UTF-16 length.
         let cleaned = emoji_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             // Calculate the number of UTF-16 code units.
             let utf16_length = caps[0].encode_utf16().count();
             " ".repeat(utf16_length)
         });
-    
+
         // Step 9: Replace sequences of two or more dashes with spaces.
         let cleaned = dash_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             " ".repeat(caps[0].chars().count())
         });
-    
+
         // Step 10: Replace non-English words, preserving grapheme cluster length.
         let cleaned = non_english_words_regex.replace_all(&cleaned, |caps: &regex::Captures| {
             " ".repeat(caps[0].chars().count())
         });
-    
+
         cleaned.to_string()
     }
-    
+
     /// Perform the configured linting on the provided text.
     pub fn lint(&mut self, text: String) -> Vec<Lint> {
         let source: Vec<_> = text.chars().collect();
         let source = Lrc::new(source);
 
-        let document =
-            Document::new_from_vec(source.clone(), &mut PlainEnglish, &FullDictionary::curated());
+        let document = Document::new_from_vec(
+            source.clone(),
+            &mut PlainEnglish,
+            &FullDictionary::curated(),
+        );
 
         let mut lints = self.lint_group.lint(&document);
 
@@ -236,6 +293,41 @@ impl Linter {
     }
 }
 
+impl Linter {
+    fn readability_report(&self, text: &str) -> ReadabilityReport {
+        let document = Document::new_plain_english(text, &self.dictionary);
+        ReadabilityReport::new(ReadabilityStats::from_document(&document))
+    }
+}
+
+/// The counts and scores computed by [`Linter::get_readability_stats`].
+#[derive(Debug, Serialize)]
+struct ReadabilityReport {
+    sentences: usize,
+    words: usize,
+    syllables: usize,
+    complex_words: usize,
+    average_sentence_length: f32,
+    flesch_reading_ease: f32,
+    flesch_kincaid_grade: f32,
+    gunning_fog: f32,
+}
+
+impl ReadabilityReport {
+    fn new(stats: ReadabilityStats) -> Self {
+        Self {
+            sentences: stats.sentences,
+            words: stats.words,
+            syllables: stats.syllables,
+            complex_words: stats.complex_words,
+            average_sentence_length: stats.average_sentence_length(),
+            flesch_reading_ease: stats.flesch_reading_ease(),
+            flesch_kincaid_grade: stats.flesch_kincaid_grade(),
+            gunning_fog: stats.gunning_fog(),
+        }
+    }
+}
+
 impl Default for Linter {
     fn default() -> Self {
         Self::new()
@@ -267,6 +359,9 @@ pub struct Suggestion {
 pub enum SuggestionKind {
     Replace = 0,
     Remove = 1,
+    InsertBefore = 2,
+    InsertAfter = 3,
+    Edits = 4,
 }
 
 #[wasm_bindgen]
@@ -278,10 +373,19 @@ impl Suggestion {
     /// Get the text that is going to replace error.
     /// If [`Self::kind`] is `SuggestionKind::Remove`, this will return an empty
     /// string.
+    /// For insertions, this is the text that will be inserted.
+    /// For `SuggestionKind::Edits`, this is the text of each edit, joined by spaces.
     pub fn get_replacement_text(&self) -> String {
         match &self.inner {
             harper_core::linting::Suggestion::Remove => "".to_string(),
-            harper_core::linting::Suggestion::ReplaceWith(chars) => chars.iter().collect(),
+            harper_core::linting::Suggestion::ReplaceWith(chars)
+            | harper_core::linting::Suggestion::InsertBefore(chars)
+            | harper_core::linting::Suggestion::InsertAfter(chars) => chars.iter().collect(),
+            harper_core::linting::Suggestion::Edits(edits) => edits
+                .iter()
+                .map(|e| e.text.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join(" "),
         }
     }
 
@@ -289,6 +393,9 @@ impl Suggestion {
         match &self.inner {
             harper_core::linting::Suggestion::Remove => SuggestionKind::Remove,
             harper_core::linting::Suggestion::ReplaceWith(_) => SuggestionKind::Replace,
+            harper_core::linting::Suggestion::InsertBefore(_) => SuggestionKind::InsertBefore,
+            harper_core::linting::Suggestion::InsertAfter(_) => SuggestionKind::InsertAfter,
+            harper_core::linting::Suggestion::Edits(_) => SuggestionKind::Edits,
         }
     }
 }
@@ -316,6 +423,11 @@ impl Lint {
         self.inner.lint_kind.to_string()
     }
 
+    /// Get the stable identifier of the rule that produced the lint.
+    pub fn rule_id(&self) -> String {
+        self.inner.rule_id.clone()
+    }
+
     pub fn suggestion_count(&self) -> usize {
         self.inner.suggestions.len()
     }
@@ -65,6 +65,16 @@ for (const [linterName, Linter] of Object.entries(linters)) {
 		expect(result).toBe(false);
 	});
 
+	test(`${linterName} computes readability stats`, async () => {
+		const linter = new Linter();
+
+		const stats = await linter.getReadabilityStats('The cat sat on the mat. It was happy.');
+
+		expect(stats.sentences).toBe(2);
+		expect(stats.words).toBe(9);
+		expect(stats.flesch_reading_ease).toBeTypeOf('number');
+	});
+
 	test(`${linterName} can run setup without issues`, async () => {
 		const linter = new Linter();
 
@@ -94,6 +104,18 @@ for (const [linterName, Linter] of Object.entries(linters)) {
 			expect(lintConfig[key]).toBe(true);
 		}
 	});
+
+	test(`${linterName} can follow a style preset`, async () => {
+		const linter = new Linter();
+
+		expect(await linter.getStylePresets()).toContain('google-dev-docs');
+		expect(await linter.lint('The report was written by the team.')).toHaveLength(0);
+
+		await linter.setStylePreset('google-dev-docs');
+
+		expect(await linter.getStylePreset()).toBe('google-dev-docs');
+		expect(await linter.lint('The report was written by the team.')).not.toHaveLength(0);
+	});
 }
 
 test('Linters have the same config format', async () => {
@@ -1,5 +1,5 @@
 import type { Lint, Span, Suggestion } from 'wasm';
-import { LintConfig } from './main';
+import { LintConfig, LintDescription, ReadabilityStats } from './main';
 
 /** A interface for an object that can perform linting actions. */
 export default interface Linter {
@@ -29,4 +29,26 @@ export default interface Linter {
 
 	/** Set the linter's current configuration from JSON. */
 	setLintConfigWithJSON(config: string): Promise<void>;
+
+	/** Get the names of the available style presets, like `google-dev-docs`. */
+	getStylePresets(): Promise<string[]>;
+
+	/** Get the style preset the linter follows, if any. */
+	getStylePreset(): Promise<string | undefined>;
+
+	/** Follow a style preset, or none at all.
+	 * Any other settings in the lint config take precedence over the preset's. */
+	setStylePreset(preset: string | undefined): Promise<void>;
+
+	/** Get the name, description, examples and default state of every available rule. */
+	getLintDescriptions(): Promise<LintDescription[]>;
+
+	/** Get the descriptions of every available rule as JSON. */
+	getLintDescriptionsAsJSON(): Promise<string>;
+
+	/** Compute readability metrics, like the Flesch reading ease, for the provided text. */
+	getReadabilityStats(text: string): Promise<ReadabilityStats>;
+
+	/** Compute readability metrics for the provided text as JSON. */
+	getReadabilityStatsAsJSON(text: string): Promise<string>;
 }
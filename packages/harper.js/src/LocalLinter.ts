@@ -1,7 +1,7 @@
 import type { Lint, Span, Suggestion, Linter as WasmLinter } from 'wasm';
 import Linter from './Linter';
 import loadWasm from './loadWasm';
-import { LintConfig } from './main';
+import { LintConfig, LintDescription, ReadabilityStats } from './main';
 
 /** A Linter that runs in the current JavaScript context (meaning it is allowed to block the event loop). */
 export default class LocalLinter implements Linter {
@@ -69,4 +69,46 @@ export default class LocalLinter implements Linter {
 
 		this.inner!.set_lint_config_from_json(config);
 	}
+
+	async getStylePresets(): Promise<string[]> {
+		await this.initialize();
+
+		return this.inner!.get_style_presets();
+	}
+
+	async getStylePreset(): Promise<string | undefined> {
+		await this.initialize();
+
+		return this.inner!.get_style_preset();
+	}
+
+	async setStylePreset(preset: string | undefined): Promise<void> {
+		await this.initialize();
+
+		this.inner!.set_style_preset(preset);
+	}
+
+	async getLintDescriptions(): Promise<LintDescription[]> {
+		await this.initialize();
+
+		return this.inner!.get_lint_descriptions();
+	}
+
+	async getLintDescriptionsAsJSON(): Promise<string> {
+		await this.initialize();
+
+		return this.inner!.get_lint_descriptions_as_json();
+	}
+
+	async getReadabilityStats(text: string): Promise<ReadabilityStats> {
+		await this.initialize();
+
+		return this.inner!.get_readability_stats(text);
+	}
+
+	async getReadabilityStatsAsJSON(text: string): Promise<string> {
+		await this.initialize();
+
+		return this.inner!.get_readability_stats_as_json(text);
+	}
 }
@@ -3,7 +3,7 @@ import type { Lint, Suggestion, Span } from 'wasm';
 import Linter from '../Linter';
 import Worker from './worker.js?worker&inline';
 import { getWasmUri } from '../loadWasm';
-import { LintConfig } from '../main';
+import { LintConfig, LintDescription, ReadabilityStats } from '../main';
 
 /** The data necessary to complete a request once the worker has responded. */
 type RequestItem = {
@@ -92,6 +92,34 @@ export default class WorkerLinter implements Linter {
 		return await this.rpc('setLintConfigWithJSON', [config]);
 	}
 
+	async getStylePresets(): Promise<string[]> {
+		return await this.rpc('getStylePresets', []);
+	}
+
+	async getStylePreset(): Promise<string | undefined> {
+		return await this.rpc('getStylePreset', []);
+	}
+
+	async setStylePreset(preset: string | undefined): Promise<void> {
+		return await this.rpc('setStylePreset', [preset]);
+	}
+
+	async getLintDescriptions(): Promise<LintDescription[]> {
+		return JSON.parse(await this.getLintDescriptionsAsJSON());
+	}
+
+	async getLintDescriptionsAsJSON(): Promise<string> {
+		return await this.rpc('getLintDescriptionsAsJSON', []);
+	}
+
+	async getReadabilityStats(text: string): Promise<ReadabilityStats> {
+		return JSON.parse(await this.getReadabilityStatsAsJSON(text));
+	}
+
+	async getReadabilityStatsAsJSON(text: string): Promise<string> {
+		return await this.rpc('getReadabilityStatsAsJSON', [text]);
+	}
+
 	/** Run a procedure on the remote worker. */
 	private async rpc(procName: string, args: any[]): Promise<any> {
 		const promise = new Promise((resolve, reject) => {
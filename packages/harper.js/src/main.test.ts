@@ -5,4 +5,7 @@ import { SuggestionKind } from './main';
 test('Wasm and JS SuggestionKinds agree', async () => {
 	expect(SuggestionKind.Remove).toBe(WasmSuggestionKind.Remove);
 	expect(SuggestionKind.Replace).toBe(WasmSuggestionKind.Replace);
+	expect(SuggestionKind.InsertBefore).toBe(WasmSuggestionKind.InsertBefore);
+	expect(SuggestionKind.InsertAfter).toBe(WasmSuggestionKind.InsertAfter);
+	expect(SuggestionKind.Edits).toBe(WasmSuggestionKind.Edits);
 });
@@ -8,7 +8,38 @@ export type { Linter, Lint, Span, Suggestion };
 
 export enum SuggestionKind {
 	Replace = 0,
-	Remove = 1
+	Remove = 1,
+	InsertBefore = 2,
+	InsertAfter = 3,
+	Edits = 4
 }
 
-export type LintConfig = Record<string, boolean | undefined>;
+/** Either a plain toggle, or a set of options for linters that accept them. */
+export type LinterSetting = boolean | ({ enabled?: boolean } & Record<string, unknown>);
+
+/** Linter settings, keyed by rule. The `dialect` and `preset` keys take strings. */
+export type LintConfig = Record<string, LinterSetting | string | undefined>;
+
+/** Documentation for a rule, as returned by `Linter.getLintDescriptions`. */
+export interface LintDescription {
+	rule_id: string;
+	default_enabled: boolean;
+	name: string;
+	summary: string;
+	long_description: string;
+	lint_kind: string;
+	good_examples: string[];
+	bad_examples: string[];
+}
+
+/** Readability metrics for a piece of text, as returned by `Linter.getReadabilityStats`. */
+export interface ReadabilityStats {
+	sentences: number;
+	words: number;
+	syllables: number;
+	complex_words: number;
+	average_sentence_length: number;
+	flesch_reading_ease: number;
+	flesch_kincaid_grade: number;
+	gunning_fog: number;
+}